@@ -0,0 +1,132 @@
+//! Heap usage of single parts, with the `alloc-stats` feature a counting allocator is installed.
+//! The counters are global, so parts running side by side (`run -j`) count each other's allocations.
+
+#[cfg(feature = "alloc-stats")]
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+#[cfg(feature = "alloc-stats")]
+#[test]
+fn test_measure() {
+    let (v, stats) = measure(|| vec![0_u8; 100_000]);
+    let stats = stats.unwrap();
+    assert_eq!(v.len(), 100_000);
+    assert!(stats.allocations >= 1);
+    // other tests allocate at the same time, so only a lower bound
+    assert!(stats.peak_bytes >= 100_000);
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GiB");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// allocations and reallocations
+    pub allocations: u64,
+    /// most bytes on the heap at once, on top of what was there before
+    pub peak_bytes: usize,
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[cfg(feature = "alloc-stats")]
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "alloc-stats")]
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc-stats")]
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting along
+#[cfg(feature = "alloc-stats")]
+struct Counting;
+
+#[cfg(feature = "alloc-stats")]
+fn grow(bytes: usize) {
+    let now = CURRENT.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK.fetch_max(now, Ordering::Relaxed);
+}
+
+// everything is passed on to System unchanged, only the counters are added
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                CURRENT.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+            }
+        }
+        new
+    }
+}
+
+/// Heap usage while running `func`, None without the `alloc-stats` feature
+#[cfg(feature = "alloc-stats")]
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(base),
+    };
+    (result, Some(stats))
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    (func(), None)
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
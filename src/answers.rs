@@ -0,0 +1,266 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{day::DEFAULT_YEAR, input::Client, prelude::*};
+
+#[cfg(test)]
+use crate::stub_server::StubServer;
+
+#[test]
+fn test_parse_verdict() {
+    let correct = "<article><p>That's the right answer!  You are one gold star closer to restoring snow operations.</p></article>";
+    let high = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+    let low = "<article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article>";
+    let wrong = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>";
+    let wait = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+    let solved = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+
+    assert_eq!(Verdict::parse(correct).unwrap(), Verdict::Correct);
+    assert_eq!(Verdict::parse(high).unwrap(), Verdict::TooHigh);
+    assert_eq!(Verdict::parse(low).unwrap(), Verdict::TooLow);
+    assert_eq!(Verdict::parse(wrong).unwrap(), Verdict::Wrong);
+    assert_eq!(Verdict::parse(wait).unwrap(), Verdict::Wait(Duration::from_secs(83)));
+    assert_eq!(Verdict::parse(solved).unwrap(), Verdict::AlreadySolved);
+    assert!(Verdict::parse("<html>maintenance</html>").is_err());
+}
+
+#[test]
+fn test_answers_file() {
+    let path = crate::testing::temp_path("answers.txt");
+    let mut answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.check(1, Part::One, &Answer::from(142_u32)), None);
+
+    answers.record(1, Part::One, &Answer::from(142_u32), None);
+    answers.record(11, Part::Two, &Answer::from(82000210_usize), Some("00000000000000ff"));
+    answers.save().unwrap();
+
+    let mut answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.check(1, Part::One, &Answer::from(142_u32)), Some(true));
+    assert_eq!(answers.check(1, Part::One, &Answer::from(143_u32)), Some(false));
+    assert_eq!(answers.check(11, Part::Two, &Answer::from(82000210_usize)), Some(true));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1.1 142\n11.2 82000210 00000000000000ff\n");
+
+    assert_eq!(answers.input_hash(1, Part::One), None);
+    assert_eq!(answers.input_hash(11, Part::Two), Some("00000000000000ff"));
+    assert!(answers.add_input_hash(1, Part::One, "0000000000000001"));
+    assert!(!answers.add_input_hash(1, Part::One, "0000000000000002"));
+    assert!(!answers.add_input_hash(11, Part::Two, "0000000000000002"));
+    assert!(!answers.add_input_hash(2, Part::One, "0000000000000002"));
+    assert_eq!(answers.input_hash(1, Part::One), Some("0000000000000001"));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_submit() {
+    let server = StubServer::start(vec![
+        (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+        (200, "<article><p>That's the right answer!</p></article>"),
+    ]);
+    let client = Client::new(&server.url, Some("abc123".to_owned())).min_interval(Duration::ZERO);
+    let path = crate::testing::temp_path("submit.txt");
+    let mut answers = Answers::load(&path).unwrap();
+
+    let verdict = submit(&client, &mut answers, 2023, 7, Part::Two, &Answer::from(10_u64), None).unwrap();
+    assert_eq!(verdict, Verdict::TooLow);
+    assert_eq!(answers.check(7, Part::Two, &Answer::from(10_u64)), None);
+
+    let verdict = submit(&client, &mut answers, 2023, 7, Part::Two, &Answer::from(5905_u64), Some("00000000000000ff")).unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+    let answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.check(7, Part::Two, &Answer::from(5905_u64)), Some(true));
+    assert_eq!(answers.input_hash(7, Part::Two), Some("00000000000000ff"));
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("POST /2023/day/7/answer "));
+    assert!(requests[0].ends_with("level=2&answer=10"));
+    assert!(requests[1].ends_with("level=2&answer=5905"));
+
+    fs::remove_file(path).unwrap();
+}
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// `answers.txt` for 2023 like it always was, `answers-<year>.txt` for the other years
+pub fn answers_file(year: u16) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        PathBuf::from(ANSWERS_FILE)
+    } else {
+        PathBuf::from(format!("answers-{}.txt", year))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong without a hint, happens for text answers and after a few tries
+    Wrong,
+    Wait(Duration),
+    AlreadySolved,
+}
+
+impl Verdict {
+    pub fn parse(html: &str) -> Result<Self> {
+        if html.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Ok(Verdict::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Ok(Verdict::TooLow)
+            } else {
+                Ok(Verdict::Wrong)
+            }
+        } else if html.contains("You gave an answer too recently") {
+            let wait = html
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .ok_or("answer too recently, but no wait time found")?
+                .0;
+            Ok(Verdict::Wait(parse_wait(wait)?))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::AlreadySolved)
+        } else {
+            Err("unknown response to answer".into())
+        }
+    }
+}
+
+/// "1m 23s" or "45s"
+fn parse_wait(wait: &str) -> Result<Duration> {
+    let secs = wait.split_whitespace().try_fold(0, |secs, token| {
+        let (value, unit) = token.split_at(token.len() - 1);
+        let value = value.parse::<u64>()?;
+        let factor = match unit {
+            "h" => 3600,
+            "m" => 60,
+            "s" => 1,
+            _ => return Err(AocError::from(format!("unknown wait time {}", wait))),
+        };
+        Ok(secs + value * factor)
+    })?;
+    Ok(Duration::from_secs(secs))
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(wait) => write!(f, "answered too recently, wait {} s", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Known good answers, one `<day>.<part> <answer> [<input hash>]` per line
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<(u8, Part), Recorded>,
+}
+
+struct Recorded {
+    answer: String,
+    /// [crate::input::input_hash] of the input the answer is for, answers recorded before there were hashes have none
+    input_hash: Option<String>,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_owned();
+        let mut answers = BTreeMap::new();
+
+        if path.exists() {
+            for line in fs::read_to_string(&path)?.lines().filter(|l| !l.trim().is_empty()) {
+                let (id, answer) = line
+                    .split_once(' ')
+                    .ok_or(format!("invalid answer line: {}", line))?;
+                let (day, part) = id
+                    .split_once('.')
+                    .ok_or(format!("invalid answer id: {}", id))?;
+                let part = Part::try_from(part.parse::<u8>()?)?;
+                let (answer, input_hash) = match answer.trim().split_once(' ') {
+                    Some((answer, hash)) => (answer, Some(hash.trim().to_owned())),
+                    None => (answer.trim(), None),
+                };
+                answers.insert((day.parse()?, part), Recorded { answer: answer.to_owned(), input_hash });
+            }
+        }
+
+        Ok(Self { path, answers })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let content = self
+            .answers
+            .iter()
+            .map(|((day, part), recorded)| match &recorded.input_hash {
+                Some(hash) => format!("{}.{} {} {}\n", day, part, recorded.answer, hash),
+                None => format!("{}.{} {}\n", day, part, recorded.answer),
+            })
+            .collect::<String>();
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer, input_hash: Option<&str>) {
+        let recorded = Recorded {
+            answer: answer.to_string(),
+            input_hash: input_hash.map(str::to_owned),
+        };
+        self.answers.insert((day, part), recorded);
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|recorded| recorded.answer.as_str())
+    }
+
+    /// Hash of the input the recorded answer is for
+    pub fn input_hash(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part))?.input_hash.as_deref()
+    }
+
+    /// Fills in the input hash of an answer recorded without one, false if there was nothing to fill in
+    pub fn add_input_hash(&mut self, day: u8, part: Part, input_hash: &str) -> bool {
+        match self.answers.get_mut(&(day, part)) {
+            Some(recorded) if recorded.input_hash.is_none() => {
+                recorded.input_hash = Some(input_hash.to_owned());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// None if there is no known answer yet
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Option<bool> {
+        self.get(day, part).map(|known| known == answer.to_string())
+    }
+}
+
+/// Sends the answer to the puzzle site and remembers it, and the input it is for, if it was right
+pub fn submit(
+    client: &Client,
+    answers: &mut Answers,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    input_hash: Option<&str>,
+) -> Result<Verdict> {
+    let html = client.post_answer(year, day, part, &answer.to_string())?;
+    let verdict = Verdict::parse(&html)?;
+    if verdict == Verdict::Correct {
+        answers.record(day, part, answer, input_hash);
+        answers.save()?;
+    }
+    Ok(verdict)
+}
@@ -0,0 +1,181 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{day::DEFAULT_YEAR, prelude::*};
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns ");
+    assert_eq!(format_duration(Duration::from_nanos(1_500)), "1 us ");
+    assert_eq!(format_duration(Duration::from_micros(1_999)), "1 ms ");
+    assert_eq!(format_duration(Duration::from_millis(1_000)), "1 s  ");
+    assert_eq!(format_duration(Duration::from_secs(59)), "59 s  ");
+    assert_eq!(format_duration(Duration::from_secs(150)), "2.5 min");
+}
+
+#[test]
+fn test_stats() {
+    let times = [4, 1, 3, 2, 10].map(Duration::from_micros);
+    let stats = Stats::new(&times);
+    assert_eq!(stats.runs, 5);
+    assert_eq!(stats.median_ns, 3_000);
+    assert_eq!(stats.mean_ns, 4_000);
+    assert_eq!(stats.min_ns, 1_000);
+    assert_eq!(stats.stddev_ns, 3_162);
+
+    let even = Stats::new(&[1, 2, 3, 4].map(Duration::from_micros));
+    assert_eq!(even.median_ns, 2_500);
+}
+
+#[test]
+fn test_baseline() {
+    let path = crate::testing::temp_path("bench.json");
+    let mut baseline = Baseline::load(&path).unwrap();
+    assert!(baseline.compare(1, Part::One, &Stats::new(&[Duration::from_micros(10)])).is_none());
+
+    baseline.insert(1, Part::One, Stats::new(&[Duration::from_micros(10)]));
+    baseline.save(&path).unwrap();
+
+    let baseline = Baseline::load(&path).unwrap();
+    let slower = Stats::new(&[Duration::from_micros(12)]);
+    assert_eq!(baseline.compare(1, Part::One, &slower), Some(20.0));
+    assert!(baseline.compare(1, Part::Two, &slower).is_none());
+
+    fs::remove_file(path).unwrap();
+}
+
+pub const BASELINE_FILE: &str = "bench.json";
+
+/// `bench.json` for 2023, `bench-<year>.json` for the other years
+pub fn baseline_file(year: u16) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        PathBuf::from(BASELINE_FILE)
+    } else {
+        PathBuf::from(format!("bench-{}.json", year))
+    }
+}
+
+pub fn time<T>(func: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = func();
+    (result, start.elapsed())
+}
+
+/// Largest unit with at least one whole count, padded so the columns line up
+pub fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() >= 60 {
+        format!("{:.1} min", duration.as_secs_f64() / 60.0)
+    } else if duration.as_secs() >= 1 {
+        format!("{} s  ", duration.as_secs())
+    } else if duration.as_millis() >= 1 {
+        format!("{} ms ", duration.as_millis())
+    } else if duration.as_micros() >= 1 {
+        format!("{} us ", duration.as_micros())
+    } else {
+        format!("{} ns ", duration.as_nanos())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub min_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn new(times: &[Duration]) -> Self {
+        assert!(!times.is_empty(), "no benchmark runs");
+
+        let mut nanos = times.iter().map(|t| t.as_nanos() as f64).collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let runs = nanos.len();
+        let median = if runs % 2 == 0 {
+            (nanos[runs / 2 - 1] + nanos[runs / 2]) / 2.0
+        } else {
+            nanos[runs / 2]
+        };
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Self {
+            runs,
+            median_ns: median as u64,
+            mean_ns: mean as u64,
+            min_ns: nanos[0] as u64,
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let d = |ns| format_duration(Duration::from_nanos(ns));
+        write!(
+            f,
+            "median {:>8} mean {:>8} min {:>8} stddev {:>8}",
+            d(self.median_ns),
+            d(self.mean_ns),
+            d(self.min_ns),
+            d(self.stddev_ns)
+        )
+    }
+}
+
+/// Runs `func` `warmup` times without timing, then `runs` times measured
+pub fn bench<T>(warmup: usize, runs: usize, mut func: impl FnMut() -> Result<T>) -> Result<(T, Stats)> {
+    for _ in 0..warmup {
+        func()?;
+    }
+
+    let mut result = None;
+    let mut times = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        let (r, duration) = time(&mut func);
+        result = Some(r?);
+        times.push(duration);
+    }
+
+    Ok((result.unwrap(), Stats::new(&times)))
+}
+
+/// Stats of an earlier benchmark, stored as JSON keyed by `<day>.<part>`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, Stats>);
+
+impl Baseline {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, stats: Stats) {
+        self.0.insert(format!("{}.{}", day, part), stats);
+    }
+
+    /// Change of the median in percent, positive means slower than the baseline
+    pub fn compare(&self, day: u8, part: Part, stats: &Stats) -> Option<f64> {
+        let base = self.0.get(&format!("{}.{}", day, part))?;
+        if base.median_ns == 0 {
+            return None;
+        }
+        Some((stats.median_ns as f64 - base.median_ns as f64) / base.median_ns as f64 * 100.0)
+    }
+}
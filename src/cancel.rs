@@ -0,0 +1,154 @@
+//! Time budgets and Ctrl-C for single parts.
+//! Every part runs on its own thread, so a part that takes too long can be left behind.
+//! Long loops call [check] to actually stop once they are not wanted anymore.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc::{measure, AllocStats},
+    bench::time,
+    prelude::*,
+};
+
+#[cfg(test)]
+use crate::testing::TestDay;
+
+#[test]
+fn test_run_part() {
+    let (answer, _, alloc) = run_part(&TestDay, Part::One, Arc::from("abc"), None);
+    assert_eq!(answer.unwrap(), Answer::from(3_usize));
+    assert_eq!(alloc.is_some(), cfg!(feature = "alloc-stats"));
+
+    let budget = Duration::from_millis(50);
+    let (answer, duration, _) = run_part(&TestDay, Part::Two, Arc::from(""), Some(budget));
+    assert!(matches!(answer, Err(AocError::Timeout(b)) if b == budget));
+    assert!(duration >= budget);
+    assert_eq!(answer.unwrap_err().to_string(), "timed out after 0.05 s");
+}
+
+#[test]
+fn test_check() {
+    assert!(check().is_ok());
+    let token = Arc::new(AtomicBool::new(true));
+    TOKEN.with(|t| *t.borrow_mut() = Some(token));
+    assert!(matches!(check(), Err(AocError::Cancelled)));
+    TOKEN.with(|t| *t.borrow_mut() = None);
+}
+
+/// Solvers may recurse deeply on real inputs, spawned threads only get 2 MiB by default
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+/// How often a waiting part looks for Ctrl-C
+const POLL: Duration = Duration::from_millis(20);
+
+/// Parts waiting in [run_part] right now
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+/// Counts Ctrl-C presses, parts started before the last press get cancelled
+static INTERRUPTS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static TOKEN: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// Err(Cancelled) once the part running on this thread timed out or got cancelled
+pub fn check() -> Result<()> {
+    let cancelled = TOKEN.with(|token| {
+        token
+            .borrow()
+            .as_ref()
+            .is_some_and(|token| token.load(Ordering::Relaxed))
+    });
+    if cancelled {
+        Err(AocError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+/// Ctrl-C cancels the running parts and the run goes on with the next one.
+/// Pressed while no part is running, it quits like it normally would.
+pub fn handle_ctrl_c() -> Result<()> {
+    let handler = || {
+        if RUNNING.load(Ordering::SeqCst) == 0 {
+            signal_hook::low_level::exit(130);
+        }
+        INTERRUPTS.fetch_add(1, Ordering::SeqCst);
+    };
+    // only touches atomics and exits, both are fine inside a signal handler
+    unsafe { signal_hook::low_level::register(signal_hook::consts::SIGINT, handler) }?;
+    Ok(())
+}
+
+/// Solves one part on its own thread and gives up on it after `budget` or on Ctrl-C.
+/// The duration is the time spent solving, without starting the thread.
+/// Heap usage is only known for parts that finish.
+pub fn run_part(
+    solution: &'static dyn Solution,
+    part: Part,
+    input: Arc<str>,
+    budget: Option<Duration>,
+) -> (Result<Answer>, Duration, Option<AllocStats>) {
+    let token = Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = mpsc::channel();
+
+    let thread_token = token.clone();
+    let spawned = thread::Builder::new()
+        .name(format!("day {}.{}", solution.day(), part))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            TOKEN.with(|token| *token.borrow_mut() = Some(thread_token));
+            let ((result, alloc), duration) =
+                time(|| measure(|| panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, &input)))));
+            let result = result.unwrap_or_else(|payload| Err(AocError::Panic(panic_message(payload.as_ref()).to_owned())));
+            // nobody listens anymore if the part timed out
+            let _ = sender.send((result, duration, alloc));
+        });
+    if let Err(err) = spawned {
+        return (Err(err.into()), Duration::ZERO, None);
+    }
+
+    RUNNING.fetch_add(1, Ordering::SeqCst);
+    let interrupts = INTERRUPTS.load(Ordering::SeqCst);
+    let start = Instant::now();
+
+    let result = loop {
+        let wait = budget.map_or(POLL, |budget| budget.saturating_sub(start.elapsed()).min(POLL));
+        match receiver.recv_timeout(wait) {
+            Ok(result) => break result,
+            Err(RecvTimeoutError::Disconnected) => break (Err("the part stopped without an answer".into()), start.elapsed(), None),
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+
+        if INTERRUPTS.load(Ordering::SeqCst) != interrupts {
+            token.store(true, Ordering::Relaxed);
+            break (Err(AocError::Cancelled), start.elapsed(), None);
+        }
+        if let Some(budget) = budget.filter(|budget| start.elapsed() >= *budget) {
+            token.store(true, Ordering::Relaxed);
+            break (Err(AocError::Timeout(budget)), start.elapsed(), None);
+        }
+    };
+
+    RUNNING.fetch_sub(1, Ordering::SeqCst);
+    result
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown reason"
+    }
+}
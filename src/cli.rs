@@ -0,0 +1,207 @@
+use std::{ops::RangeInclusive, path::PathBuf, str::FromStr};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use aoc::{day::DEFAULT_YEAR, prelude::*};
+
+#[test]
+fn test_day_selection() {
+    assert_eq!(DaySelection::from_str("all").unwrap().days(), (1..=25).collect::<Vec<_>>());
+    assert_eq!(DaySelection::from_str("12").unwrap().days(), vec![12]);
+    assert_eq!(DaySelection::from_str("3..5").unwrap().days(), vec![3, 4, 5]);
+    assert_eq!(DaySelection::from_str("3-5").unwrap().days(), vec![3, 4, 5]);
+    assert_eq!(DaySelection::from_str("3..=5").unwrap().days(), vec![3, 4, 5]);
+}
+
+#[test]
+fn test_day_selection_invalid() {
+    assert!(DaySelection::from_str("0").is_err());
+    assert!(DaySelection::from_str("26").is_err());
+    assert!(DaySelection::from_str("5..3").is_err());
+    assert!(DaySelection::from_str("one").is_err());
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+pub struct Cli {
+    /// List the implemented days and parts
+    #[arg(long)]
+    pub list: bool,
+
+    /// Puzzle year, for every command
+    #[arg(long, global = true, default_value_t = DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+    pub year: u16,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run one day, a range of days or all days
+    Run(RunArgs),
+    /// Submit an answer and remember it in `answers.txt` when it is right
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Computed from `input/<year>/<day>.txt` if not given
+        answer: Option<String>,
+    },
+    /// Time days repeatedly and compare against a saved baseline
+    Bench(BenchArgs),
+    /// Download missing puzzle inputs into `input/<year>/`
+    Fetch {
+        #[arg(default_value = "all")]
+        days: DaySelection,
+    },
+    /// Extract the examples and their answers from a saved puzzle page into `examples/`
+    Examples {
+        /// The puzzle page, saved from the browser
+        page: PathBuf,
+    },
+    /// Create `src/day/y<year>/d<day>_<name>.rs` from a template and register it
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle title, e.g. "Camel Cards"
+        name: String,
+    },
+}
+
+#[derive(Args, Debug, Default)]
+pub struct RunArgs {
+    /// Day to run: `12`, `3..7`, `3-7` or `all`
+    #[arg(default_value = "all")]
+    pub days: DaySelection,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Input file, only allowed when a single day is selected
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// Also run parts that are known to take very long
+    #[arg(long)]
+    pub slow: bool,
+
+    /// Run all selected parts at the same time and print a summary table
+    #[arg(short = 'j', long)]
+    pub parallel: bool,
+
+    /// Give up on a part after this many seconds
+    #[arg(long, value_name = "SECONDS")]
+    pub timeout: Option<u64>,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+
+    /// Step through the grid days in the terminal: space pauses, n steps, +/- change the speed, q stops watching
+    #[arg(long, conflicts_with = "parallel")]
+    pub visual: bool,
+
+    /// Record the frames of the grid days, a GIF for `.gif` files and an asciinema cast otherwise
+    #[arg(long, value_name = "FILE", conflicts_with = "parallel")]
+    pub record: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// One line per part, or a table with --parallel
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    /// Day to benchmark: `12`, `3..7`, `3-7` or `all`
+    #[arg(default_value = "all")]
+    pub days: DaySelection,
+
+    /// Only benchmark this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Also benchmark parts that are known to take very long
+    #[arg(long)]
+    pub slow: bool,
+
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 3)]
+    pub warmup: usize,
+
+    /// Timed runs per part
+    #[arg(long, default_value_t = 10, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub runs: usize,
+
+    /// Baseline to compare against, `bench.json` or `bench-<year>.json` by default
+    #[arg(long)]
+    pub baseline: Option<PathBuf>,
+
+    /// Overwrite the baseline with this run
+    #[arg(long)]
+    pub save: bool,
+}
+
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum DaySelection {
+    #[default]
+    All,
+    Range(RangeInclusive<u8>),
+}
+
+impl DaySelection {
+    pub fn days(&self) -> Vec<u8> {
+        match self {
+            DaySelection::All => DAYS.collect(),
+            DaySelection::Range(range) => range.clone().collect(),
+        }
+    }
+
+    pub fn is_single(&self) -> bool {
+        matches!(self, DaySelection::Range(range) if range.start() == range.end())
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        let parse_day = |day: &str| -> Result<u8> {
+            let day = day.trim().parse::<u8>()?;
+            if !DAYS.contains(&day) {
+                return Err(format!("day {} is not in {:?}", day, DAYS).into());
+            }
+            Ok(day)
+        };
+
+        let range = if let Some((start, end)) = s.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = s.split_once('-') {
+            parse_day(start)?..=parse_day(end)?
+        } else {
+            let day = parse_day(s)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(format!("empty day range {}", s).into());
+        }
+        Ok(Self::Range(range))
+    }
+}
@@ -1,25 +1,150 @@
-pub mod d1_trebuchet;
-pub mod d2_cube_conundrum;
-pub mod d3_gear_ratios;
-pub mod d4_scratchcards;
-pub mod d5_fertilizer;
-pub mod d6_wait_for_it;
-pub mod d7_camel_cards;
-pub mod d8_haunted_wasteland;
-pub mod d9_mirage_maintenance;
-pub mod d10_pipe_maze;
-pub mod d11_cosmic_expansion;
-pub mod d12_hot_springs;
-pub mod d13_point_of_incidence;
-pub mod d14_parabolic_reflector_dish;
-pub mod d15_lens_library;
-pub mod d16_the_floor_will_be_lava;
-pub mod d17_clumsy_crucible;
-pub mod d18_lavaduct_lagoon;
-pub mod d19_aplenty;
-pub mod d20_pulse_propagation;
-pub mod d21_step_counter;
-pub mod d22_sand_slabs;
-pub mod d23_a_long_walk;
-pub mod d24_never_tell_me_the_odds;
-pub mod d25_snowverload;
\ No newline at end of file
+pub mod y2023;
+
+use std::{collections::BTreeMap, fmt::Display, sync::OnceLock};
+
+use enum_iterator::Sequence;
+
+use crate::prelude::*;
+
+#[test]
+fn test_registry() {
+    let days = solutions(2023).iter().map(|s| s.day()).collect::<Vec<_>>();
+    assert_eq!(days, (1..=25).collect::<Vec<_>>());
+    assert_eq!(solution(2023, 11).unwrap().day(), 11);
+    assert!(solution(2023, 26).is_none());
+    assert!(solutions(2015).is_empty());
+    assert!(years().any(|year| year == DEFAULT_YEAR));
+}
+
+#[test]
+fn test_solution() {
+    let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+    let day1 = &solutions(2023)[0];
+    assert_eq!(day1.solve(Part::One, input).unwrap(), Answer::from(142_u32));
+    assert_eq!(day1.solve(Part::One, input).unwrap().to_string(), "142");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Sequence)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = AocError;
+
+    fn try_from(part: u8) -> Result<Self> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("there is no part {}", part).into()),
+        }
+    }
+}
+
+/// Every day returns a different integer type, so answers are compared as text or signed numbers
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u32, u64, usize, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Done,
+    /// correct, but takes seconds to minutes
+    Slow,
+    /// not solved yet, running it fails or panics
+    Todo,
+}
+
+/// One day of the calendar. Puzzle parameters (expansion rate, step count, ...) live in the implementing struct.
+pub trait Solution: Send + Sync {
+    fn day(&self) -> u8;
+
+    fn part1(&self, input: &str) -> Result<Answer>;
+
+    fn part2(&self, _input: &str) -> Result<Answer> {
+        Err("part 2 is not implemented".into())
+    }
+
+    fn status(&self, _part: Part) -> Status {
+        Status::Done
+    }
+
+    fn solve(&self, part: Part, input: &str) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Creates the solutions of a year
+type YearSolutions = fn() -> Vec<Box<dyn Solution>>;
+
+/// Every year with solutions, each one in its own `y<year>` module
+const YEARS: &[(u16, YearSolutions)] = &[
+    (2023, y2023::solutions),
+];
+
+pub fn years() -> impl Iterator<Item = u16> {
+    YEARS.iter().map(|(year, _)| *year)
+}
+
+/// Created once, parts run on their own threads and need the solutions to stay around.
+/// Empty for years without solutions.
+pub fn solutions(year: u16) -> &'static [Box<dyn Solution>] {
+    static SOLUTIONS: OnceLock<BTreeMap<u16, Vec<Box<dyn Solution>>>> = OnceLock::new();
+    SOLUTIONS
+        .get_or_init(|| YEARS.iter().map(|(year, solutions)| (*year, solutions())).collect())
+        .get(&year)
+        .map_or(&[], |solutions| solutions.as_slice())
+}
+
+pub fn solution(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    solutions(year).iter().find(|solution| solution.day() == day).map(|solution| solution.as_ref())
+}
@@ -0,0 +1,225 @@
+use crate::{geometry::{Direction, Pos}, grid::{Cell, Grid}, prelude::*};
+
+use core::panic;
+use std::{collections::HashMap, fmt::Debug};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Location {
+    Inner,
+    Outter,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TileType {
+    Pipe(Pipe),
+    Ground(Option<Location>),
+    Start(Option<Pipe>),
+}
+
+impl TileType {
+    pub fn walk(&self, walking_dir: &Direction) -> Result<Direction> {
+        match (self, walking_dir) {
+            (TileType::Pipe(Pipe::Vertical), Direction::Up) => Ok(Direction::Up),
+            (TileType::Pipe(Pipe::Vertical), Direction::Down) => Ok(Direction::Down),
+            (TileType::Pipe(Pipe::Horizontal), Direction::Left) => Ok(Direction::Left),
+            (TileType::Pipe(Pipe::Horizontal), Direction::Right) => Ok(Direction::Right),
+            (TileType::Pipe(Pipe::NorthEast), Direction::Down) => Ok(Direction::Right),
+            (TileType::Pipe(Pipe::NorthEast), Direction::Left) => Ok(Direction::Up),
+            (TileType::Pipe(Pipe::NorthWest), Direction::Down) => Ok(Direction::Left),
+            (TileType::Pipe(Pipe::NorthWest), Direction::Right) => Ok(Direction::Up),
+            (TileType::Pipe(Pipe::SouthEast), Direction::Up) => Ok(Direction::Right),
+            (TileType::Pipe(Pipe::SouthEast), Direction::Left) => Ok(Direction::Down),
+            (TileType::Pipe(Pipe::SouthWest), Direction::Up) => Ok(Direction::Left),
+            (TileType::Pipe(Pipe::SouthWest), Direction::Right) => Ok(Direction::Down),
+            (TileType::Ground(_), _) => Err("On Ground".into()),
+            (TileType::Start(_), dir) => Ok(*dir),
+            (tile, dir) => Err(format!("Pipe blocked walkin {:?} to {:?}", dir, tile).into()),
+        }
+    }
+
+    fn is_uptile(&self) -> bool {
+        matches!(
+            self,
+            TileType::Pipe(Pipe::NorthEast)
+                | TileType::Pipe(Pipe::SouthEast)
+                | TileType::Start(Some(Pipe::NorthEast))
+                | TileType::Start(Some(Pipe::SouthEast))
+        )
+    }
+
+    fn is_downtile(&self) -> bool {
+        matches!(
+            self,
+            TileType::Pipe(Pipe::NorthWest)
+                | TileType::Pipe(Pipe::SouthWest)
+                | TileType::Start(Some(Pipe::NorthWest))
+                | TileType::Start(Some(Pipe::SouthWest))
+        )
+    }
+
+    fn get_pipe(&self) -> Pipe {
+        match self {
+            TileType::Pipe(pipe) => *pipe,
+            TileType::Start(Some(pipe)) => *pipe,
+            _ => panic!("Not a pipe: {:?}", self),
+        }
+    }
+
+    pub fn is_start(&self) -> bool {
+        matches!(self, TileType::Start(_))
+    }
+}
+
+impl Cell for TileType {
+    const EXPECTED: &'static str = "a pipe out of -|LJF7, '.' or 'S'";
+
+    fn from_char(value: char) -> Option<Self> {
+        let tile = match value {
+            '-' => TileType::Pipe(Pipe::Horizontal),
+            '|' => TileType::Pipe(Pipe::Vertical),
+            'L' => TileType::Pipe(Pipe::NorthEast),
+            'J' => TileType::Pipe(Pipe::NorthWest),
+            'F' => TileType::Pipe(Pipe::SouthEast),
+            '7' => TileType::Pipe(Pipe::SouthWest),
+            '.' => TileType::Ground(None),
+            'S' => TileType::Start(None),
+            _ => return None,
+        };
+        Some(tile)
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            TileType::Pipe(Pipe::Vertical) => '|',
+            TileType::Pipe(Pipe::Horizontal) => '-',
+            TileType::Pipe(Pipe::NorthEast) => 'L',
+            TileType::Pipe(Pipe::NorthWest) => 'J',
+            TileType::Pipe(Pipe::SouthEast) => 'F',
+            TileType::Pipe(Pipe::SouthWest) => '7',
+            TileType::Ground(None) => '.',
+            TileType::Ground(Some(Location::Outter)) => 'O',
+            TileType::Ground(Some(Location::Inner)) => 'I',
+            TileType::Start(_) => 'S',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Tile {
+    pub typ: TileType,
+    pub pos: Pos,
+}
+
+pub struct Maze {
+    pub maze: Grid<Tile>,
+    pub start: Tile,
+}
+
+impl Debug for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.maze.map(|tile| tile.typ))
+    }
+}
+
+impl Maze {
+    pub fn cleanup(&mut self, main_pipe: &HashMap<Pos, Tile>) {
+        self.maze
+            .iter_mut()
+            .map(|(_, tile)| tile)
+            .filter(|tile| !main_pipe.contains_key(&tile.pos))
+            .for_each(|tile| {
+                tile.typ = TileType::Ground(None);
+            });
+    }
+
+    pub fn get_tile(&mut self, pos: &Pos) -> Option<&mut Tile> {
+        self.maze.get_mut(*pos)
+    }
+
+    /// The tile next to `pos`, None when walking out of the maze
+    pub fn next_tile(&mut self, pos: &Pos, dir: &Direction) -> Option<&mut Tile> {
+        let next = self.maze.step(*pos, *dir)?;
+        self.get_tile(&next)
+    }
+
+    ///Maze should be cleaned up before calling this
+    ///returns the number of inner tiles
+    pub fn define_ground(&mut self) -> usize {
+        self.maze.rows_iter_mut().for_each(|row| {
+            row.iter_mut()
+                .fold((0, None), |(vertical_cnt, up_tile), tile| match tile.typ {
+                    TileType::Ground(_) => {
+                        let is_outer = vertical_cnt % 2 == 0;
+                        let cur_loc = if is_outer {
+                            Location::Outter
+                        } else {
+                            Location::Inner
+                        };
+                        tile.typ = TileType::Ground(Some(cur_loc));
+                        (vertical_cnt, None)
+                    }
+                    TileType::Pipe(Pipe::Vertical) | TileType::Start(Some(Pipe::Vertical)) => (vertical_cnt + 1, None),
+                    up_tile if up_tile.is_uptile() => (vertical_cnt, Some(up_tile.get_pipe())),
+                    down_tile if down_tile.is_downtile() => {
+                        match (up_tile.unwrap(), down_tile.get_pipe()) {
+                            (Pipe::NorthEast, Pipe::NorthWest) => (vertical_cnt, None),
+                            (Pipe::NorthEast, Pipe::SouthWest) => (vertical_cnt + 1, None),
+                            (Pipe::SouthEast, Pipe::SouthWest) => (vertical_cnt, None),
+                            (Pipe::SouthEast, Pipe::NorthWest) => (vertical_cnt + 1, None),
+                            _ => panic!("Invalid pipe: {:?} {:?}", up_tile, down_tile),
+                        }
+                    }
+                    _ => (vertical_cnt, up_tile),
+                });
+        });
+
+        self.maze
+            .iter()
+            .filter(|(_, tile)| matches!(tile.typ, TileType::Ground(Some(Location::Inner))))
+            .count()
+    }
+}
+
+impl Maze {
+    pub fn parse(input: &str) -> Result<Self> {
+        let source = Source::new(10, input);
+        let tiles: Grid<TileType> = Grid::parse(&source, input)?;
+        let maze = Grid::from_rows(
+            tiles
+                .rows_iter()
+                .enumerate()
+                .map(|(row, types)| {
+                    types
+                        .iter()
+                        .enumerate()
+                        .map(|(col, typ)| Tile { typ: *typ, pos: Pos::new(row, col) })
+                        .collect()
+                })
+                .collect(),
+        )
+        .expect("same size as the parsed grid");
+
+        let starts = maze
+            .iter()
+            .filter(|(_, tile)| tile.typ.is_start())
+            .map(|(_, tile)| *tile)
+            .collect::<Vec<_>>();
+        let start = match starts[..] {
+            [start] => start,
+            [] => return Err(source.error(input, "a start tile 'S'")),
+            _ => return Err(source.error(input, "a single start tile 'S'")),
+        };
+
+        Ok(Self { maze, start })
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use crate::{geometry::{Direction, Pos}, prelude::*};
+#[cfg(test)]
+use proptest::prelude::*;
+mod maze;
+mod runner;
+
+use maze::*;
+use runner::*;
+
+#[allow(dead_code)]
+#[test]
+fn test_maze_distance() {
+    let input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+    assert_eq!(4, cal_maze_distance(input).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    let input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+    crate::input::check_variants(input, cal_maze_distance);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day10);
+}
+
+#[test]
+fn test_maze_distance2() {
+    let input = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+    assert_eq!(8, cal_maze_distance(input).unwrap());
+}
+
+#[test]
+fn test_enclosed_tiles() {
+    let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    assert_eq!(4, cal_enclosed_tiles(input).unwrap());
+}
+
+#[test]
+fn test_enclosed_tiles2() {
+    let input = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    assert_eq!(8, cal_enclosed_tiles(input).unwrap());
+}
+
+#[test]
+fn test_enclosed_tiles3() {
+    let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    assert_eq!(10, cal_enclosed_tiles(input).unwrap());
+}
+
+/// A loop around columns of `heights`, with junk pipes around and inside it and the start somewhere on the loop.
+/// Walked on plain (row, col) tuples like before the shared geometry, returns the maze, the loop length and the enclosed tiles.
+#[cfg(test)]
+fn histogram_maze(heights: &[usize], junk: &str, start: usize) -> (String, usize, usize) {
+    let bottom = *heights.iter().max().unwrap();
+    let mut points = vec![(bottom, 0)];
+    let mut walk_to = |target: (usize, usize)| {
+        while let Some(&(row, col)) = points.last().filter(|last| **last != target) {
+            let next = (
+                if target.0 < row { row - 1 } else if target.0 > row { row + 1 } else { row },
+                if target.1 < col { col - 1 } else if target.1 > col { col + 1 } else { col },
+            );
+            points.push(next);
+        }
+    };
+    for (col, height) in heights.iter().enumerate() {
+        walk_to((bottom - height, col));
+        walk_to((bottom - height, col + 1));
+    }
+    walk_to((bottom, heights.len()));
+    walk_to((bottom, 1));
+
+    // one junk tile around everything
+    let (rows, cols) = (bottom + 3, heights.len() + 3);
+    let mut maze = junk.chars().cycle().take(rows * cols).collect::<Vec<_>>();
+    for (i, &(row, col)) in points.iter().enumerate() {
+        let prev = points[(i + points.len() - 1) % points.len()];
+        let next = points[(i + 1) % points.len()];
+        let up = prev.0 < row || next.0 < row;
+        let down = prev.0 > row || next.0 > row;
+        let left = prev.1 < col || next.1 < col;
+        maze[(row + 1) * cols + col + 1] = match (up, down, left) {
+            (true, true, _) => '|',
+            (false, false, _) => '-',
+            (true, false, false) => 'L',
+            (true, false, true) => 'J',
+            (false, true, true) => '7',
+            (false, true, false) => 'F',
+        };
+    }
+    let (row, col) = points[start % points.len()];
+    maze[(row + 1) * cols + col + 1] = 'S';
+    // the start must not connect to junk, it would be ambiguous
+    for (row, col) in [(row, col + 1), (row + 2, col + 1), (row + 1, col), (row + 1, col + 2)] {
+        if !points.iter().any(|point| (point.0 + 1, point.1 + 1) == (row, col)) {
+            maze[row * cols + col] = '.';
+        }
+    }
+
+    let input = maze.chunks(cols).map(|row| row.iter().collect::<String>()).collect::<Vec<_>>().join("\n");
+    // between two columns everything below the lower one is inside
+    let enclosed = heights.windows(2).map(|pair| pair[0].min(pair[1]) - 1).sum();
+    (input, points.len(), enclosed)
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_histogram_maze(
+        heights in prop::collection::vec(1..5_usize, 1..6),
+        junk in "[-|LJ7F.]{1,40}",
+        start in 0..100_usize,
+    ) {
+        let (input, len, enclosed) = histogram_maze(&heights, &junk, start);
+        prop_assert_eq!(cal_maze_distance(&input).unwrap(), len / 2);
+        prop_assert_eq!(cal_enclosed_tiles(&input).unwrap(), enclosed);
+    }
+}
+
+fn get_main_pipe(maze: &mut Maze) -> HashMap<Pos, Tile> {
+    for dir in enum_iterator::all::<Direction>() {
+        let runner = MazeRunner::new(maze, maze.start, dir);
+        let (path, is_finished) = runner
+            .fold((HashMap::new(), false), |(mut map, _), (tile, _)| {
+                map.insert(tile.pos, tile);
+                let finished = tile.typ.is_start();
+
+                (map, finished)
+            });
+
+        if is_finished {
+            return path;
+        }
+    }
+    panic!("No main pipe found");
+}
+
+pub fn cal_maze_distance(input: &str) -> Result<usize> {
+    let mut maze = Maze::parse(input)?;
+    let len = get_main_pipe(&mut maze).len();
+    // println!("{:#?}", maze);
+    Ok(len / 2)
+}
+
+pub fn cal_enclosed_tiles(input: &str) -> Result<usize> {
+    let mut maze = Maze::parse(input)?;
+    let main_pipe = get_main_pipe(&mut maze);
+    maze.cleanup(&main_pipe);
+    // println!("{:#?}", maze);
+    let inner_tiles = maze.define_ground();
+    Ok(inner_tiles)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_maze_distance(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_enclosed_tiles(input)?.into())
+    }
+}
@@ -1,4 +1,6 @@
-use crate::d10_pipe_maze::maze::*;
+use crate::geometry::Direction;
+
+use super::maze::*;
 
 pub struct MazeRunner<'a> { 
     maze: &'a mut Maze,
@@ -22,22 +24,23 @@ impl<'a> MazeRunner<'a> {
     // TODO move to maze
     fn cal_start_pipe(&self) -> Pipe {
         // HACK do this with notoperator somehow
+        // walking_dir is the direction the loop came back in, a straight start keeps it
         match (self.starting_dir, self.walking_dir) {
-            (Direction::North, Direction::East) => Pipe::NorthWest,
-            (Direction::North, Direction::South) => Pipe::Vertical,
-            (Direction::North, Direction::West) => Pipe::NorthEast,
+            (Direction::Up, Direction::Right) => Pipe::NorthWest,
+            (Direction::Up, Direction::Up) => Pipe::Vertical,
+            (Direction::Up, Direction::Left) => Pipe::NorthEast,
 
-            (Direction::East, Direction::North) => Pipe::SouthEast,
-            (Direction::East, Direction::South) => Pipe::NorthEast,
-            (Direction::East, Direction::West) => Pipe::Horizontal,
+            (Direction::Right, Direction::Up) => Pipe::SouthEast,
+            (Direction::Right, Direction::Down) => Pipe::NorthEast,
+            (Direction::Right, Direction::Right) => Pipe::Horizontal,
 
-            (Direction::South, Direction::North) => Pipe::Vertical,
-            (Direction::South, Direction::East) => Pipe::SouthWest,
-            (Direction::South, Direction::West) => Pipe::SouthEast,
+            (Direction::Down, Direction::Down) => Pipe::Vertical,
+            (Direction::Down, Direction::Right) => Pipe::SouthWest,
+            (Direction::Down, Direction::Left) => Pipe::SouthEast,
 
-            (Direction::West, Direction::North) => Pipe::SouthWest,
-            (Direction::West, Direction::East) => Pipe::Horizontal,
-            (Direction::West, Direction::South) => Pipe::NorthWest,
+            (Direction::Left, Direction::Up) => Pipe::SouthWest,
+            (Direction::Left, Direction::Left) => Pipe::Horizontal,
+            (Direction::Left, Direction::Down) => Pipe::NorthWest,
         
             _ => panic!("Invalid start pipe"),
         }
@@ -62,8 +65,9 @@ impl Iterator for MazeRunner<'_> {
         }
         self.started = true;
 
-        let new_step = self.pos.walk(&self.walking_dir).map(|(new_dir, new_pos)| {
-            let new_tile = self.maze.get_tile(&new_pos);
+        let pos = self.pos.pos;
+        let new_step = self.pos.typ.walk(&self.walking_dir).map(|new_dir| {
+            let new_tile = self.maze.next_tile(&pos, &new_dir);
             (new_tile, new_dir)
         });
 
@@ -1,6 +1,6 @@
 use std::{collections::{HashSet, HashMap}, fmt::Debug};
 
-use crate::prelude::*;
+use crate::{geometry::Pos, grid::{Cell, Grid}, prelude::*};
 
 #[allow(dead_code)]
 const INPUT: &str = 
@@ -20,6 +20,16 @@ fn test_sum_of_paths_2() {
     assert_eq!(374, cal_sum_of_paths(INPUT, 2).unwrap());
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, |input| cal_sum_of_paths(input, 2));
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day11 { expansion_rate: 2, older_expansion_rate: 100 });
+}
+
 #[test]
 fn test_sum_of_paths_10() {
     assert_eq!(1030, cal_sum_of_paths(INPUT, 10).unwrap());
@@ -36,31 +46,26 @@ enum Element {
     Galaxy,
 }
 
-impl From<char> for Element {
-    fn from(c: char) -> Self {
+impl Cell for Element {
+    const EXPECTED: &'static str = "'.' or '#'";
+
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '.' => Element::Empty,
-            '#' => Element::Galaxy,
-            _ => panic!("invalid char"),
+            '.' => Some(Element::Empty),
+            '#' => Some(Element::Galaxy),
+            _ => None,
         }
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Coordinate {
-    row: usize,
-    col: usize,
-
-}
-
-impl Coordinate {
-    fn distance(&self, other: &Self) -> usize {
-        let dx = self.row as isize - other.row as isize;
-        let dy = self.col as isize - other.col as isize;
-        (dx.abs() + dy.abs()) as usize
+    fn to_char(&self) -> char {
+        match self {
+            Element::Empty => '.',
+            Element::Galaxy => '#',
+        }
     }
 }
 
+type Coordinate = Pos;
 type Start = Coordinate;
 struct Universe {
     row_len: usize,
@@ -122,36 +127,26 @@ impl Debug for Universe {
     }
 }
 
-impl From<&str> for Universe {
-    fn from(input: &str) -> Self {
-       let galaxies = input.lines()
-       .enumerate() 
-       .flat_map(|(col_idx, line)| {
-            line.chars()
-            .enumerate()
-            .map(move |(row_idx, c)| {
-                let coord = Coordinate {
-                    row: row_idx,
-                    col: col_idx,
-                };
-                (Element::from(c), coord)
-            })
-       })
-        .filter(|(element, _)| *element == Element::Galaxy)
-        .map(|(_, coord)| (coord, coord))
-        .collect::<HashMap<_, _>>();
-
-        Self {
-            row_len: input.lines().count(),
-            col_len: input.lines().next().unwrap().len(),
+impl Universe {
+    fn parse(input: &str) -> Result<Self> {
+        let image: Grid<Element> = Grid::parse(&Source::new(11, input), input)?;
+        let galaxies = image
+            .iter()
+            .filter(|(_, element)| **element == Element::Galaxy)
+            .map(|(coord, _)| (coord, coord))
+            .collect::<HashMap<_, _>>();
+
+        Ok(Self {
+            row_len: image.rows(),
+            col_len: image.cols(),
             galaxies,
-        }
+        })
     }
 }
 
 
 pub fn cal_sum_of_paths(input: &str, expansion_rate: usize) -> Result<usize> {
-    let mut universe = Universe::from(input);
+    let mut universe = Universe::parse(input)?;
 
     // println!("{:?}", universe);
 
@@ -160,7 +155,35 @@ pub fn cal_sum_of_paths(input: &str, expansion_rate: usize) -> Result<usize> {
     // println!("{:?}", universe);
     
     let sum = universe.get_combinations().iter()
-    .map(|(galaxy, other_galaxy)| galaxy.distance(other_galaxy))
+    .map(|(galaxy, other_galaxy)| galaxy.manhattan(other_galaxy))
     .sum();
     Ok(sum)
-}
\ No newline at end of file
+}
+
+pub struct Day11 {
+    pub expansion_rate: usize,
+    pub older_expansion_rate: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            expansion_rate: 2,
+            older_expansion_rate: 1_000_000,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_sum_of_paths(input, self.expansion_rate)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_sum_of_paths(input, self.older_expansion_rate)?.into())
+    }
+}
@@ -15,6 +15,16 @@ fn test_arrangement_sum() {
     assert_eq!(21, cal_arrangement_sum(INPUT).unwrap());
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_arrangement_sum);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day12);
+}
+
 #[test]
 fn test_arrangement_sum_check_last() {
     let input = ".#.?.#.# 1,1,1";
@@ -26,6 +36,14 @@ fn test_arrangement_sum_folded() {
     assert_eq!(525152, cal_arrangement_sum_folded(INPUT).unwrap());
 }
 
+#[test]
+fn test_invalid_record() {
+    let err = cal_arrangement_sum("???.### 1,1,3\n.??.x 1").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 5, .. })));
+    assert!(cal_arrangement_sum("???.### 1,a,3").is_err());
+    assert!(cal_arrangement_sum("???.###").is_err());
+}
+
 #[test]
 #[should_panic]
 fn test_slices() {
@@ -46,13 +64,13 @@ enum Spring {
     Unknown,
 }
 
-impl From<char> for Spring {
-    fn from(c: char) -> Self {
+impl Spring {
+    fn parse(c: char) -> Option<Self> {
         match c {
-            '.' => Spring::Operational,
-            '#' => Spring::Damaged,
-            '?' => Spring::Unknown,
-            _ => panic!("invalid char: {}", c),
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None,
         }
     }
 }
@@ -73,16 +91,17 @@ struct Record {
 }
 
 impl Record {
-    fn parse(input: &str, factor: usize) -> Vec<Record> {
+    fn parse(input: &str, factor: usize) -> Result<Vec<Record>> {
+        let source = Source::new(12, input);
         input
             .lines()
             .map(|line| {
-                let (springs, sizes) = line.split_once(' ').unwrap();
-                let org_springs = springs.chars().map(Spring::from).collect::<Vec<_>>();
+                let (springs, sizes) = source.split_once(line, " ")?;
+                let org_springs = source.chars(springs, "'.', '#' or '?'", Spring::parse)?;
                 let org_sizes = sizes
                     .split(',')
-                    .map(|num| num.parse().unwrap())
-                    .collect::<Vec<_>>();
+                    .map(|num| source.num(num))
+                    .collect::<Result<Vec<usize>>>()?;
 
                 let mut springs = Vec::new();
                 let mut sizes = Vec::new();
@@ -93,10 +112,10 @@ impl Record {
                 }
                 springs.pop();
 
-                Self {
+                Ok(Self {
                     springs,
                     group_sizes: sizes,
-                }
+                })
             })
             .collect()
     }
@@ -176,16 +195,39 @@ impl Record {
 
 //https://www.reddit.com/r/adventofcode/comments/18gozoj/2023_day_12_part_1_rust_i_have_no_idea_where_to/
 pub fn cal_arrangement_sum(input: &str) -> Result<usize> {
-    let records = Record::parse(input, 1);
-    let sum = records.iter().map(|r| r.different_arrangements()).sum();
-    Ok(sum)
+    let records = Record::parse(input, 1)?;
+    records
+        .iter()
+        .map(|record| {
+            crate::cancel::check()?;
+            Ok(record.different_arrangements())
+        })
+        .sum()
 }
 
 pub fn cal_arrangement_sum_folded(input: &str) -> Result<usize> {
-    let records = Record::parse(input, 5);
-    let sum = records
+    let records = Record::parse(input, 5)?;
+    records
         .iter()
-        .map(|record| record.different_arrangements())
-        .sum();
-    Ok(sum)
+        .map(|record| {
+            crate::cancel::check()?;
+            Ok(record.different_arrangements())
+        })
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_arrangement_sum(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_arrangement_sum_folded(input)?.into())
+    }
 }
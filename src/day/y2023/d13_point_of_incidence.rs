@@ -1,6 +1,4 @@
-use array2d::Array2D;
-
-use crate::prelude::*;
+use crate::{grid::{Cell, Grid}, prelude::*};
 
 #[allow(dead_code)]
 const INPUT: &str = "#.##..##.
@@ -24,23 +22,49 @@ fn test_reflection_code() {
     assert_eq!(405, cal_reflection_code(INPUT).unwrap());
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_reflection_code);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day13);
+}
+
 #[test]
 fn test_reflection_code2() {
     assert_eq!(400, cal_reflection_code2(INPUT).unwrap());
 }
 
+#[test]
+fn test_invalid_pattern() {
+    let err = cal_reflection_code("#.#\n.x.").unwrap_err();
+    assert_eq!(err.to_string(), "Parse Error: day 13, line 2, column 2: expected '.' or '#', found \"x\"");
+    assert!(cal_reflection_code("#.#\n..").is_err());
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Ash,
     Rock,
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl Cell for Tile {
+    const EXPECTED: &'static str = "'.' or '#'";
+
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '.' => Tile::Ash,
-            '#' => Tile::Rock,
-            _ => panic!("invalid char: {}", c),
+            '.' => Some(Tile::Ash),
+            '#' => Some(Tile::Rock),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Ash => '.',
+            Tile::Rock => '#',
         }
     }
 }
@@ -60,20 +84,14 @@ impl Mirror {
 }
 
 struct Pattern {
-    tiles: Array2D<Tile>,
+    tiles: Grid<Tile>,
 }
 
 impl Pattern {
-    fn parse(input: &str) -> Vec<Self> {
-        input.split("\n\n").map(|input| {
-            let patterns = input
-                .lines()
-                .map(|line| line.chars().map(Tile::from).collect())
-                .collect::<Vec<Vec<Tile>>>();
-
-            Self {
-                tiles: Array2D::from_rows(&patterns).unwrap(),
-            }
+    fn parse(input: &str) -> Result<Vec<Self>> {
+        let source = Source::new(13, input);
+        input.split("\n\n").map(|pattern| {
+            Ok(Self { tiles: Grid::parse(&source, pattern)? })
         })
         .collect()
     }
@@ -114,38 +132,20 @@ impl Pattern {
     }
 
     fn find_horizonal(&self) -> Vec<Reflection> {
-
-        self.tiles.rows_iter().enumerate().filter_map(|(i, _)| {
-            let rows = self.tiles.as_rows();
-            let (left, right) = rows.split_at(i);
-            let comp = right.iter().zip(left.iter().rev()).collect::<Vec<_>>();
-            let smudges = comp.iter()
-            .flat_map(|(rhs, lhs)| {
-                rhs.iter().zip(lhs.iter())
-            })
-            .fold(0, |smudges, (rhs, lhs)| {
-                if rhs == lhs {
-                    smudges
-                } else {
-                    smudges + 1
-                }
-            });
-
-            if !comp.is_empty() {
-                Some(Reflection{ mirror: Mirror::Horizontal(i), smudge_count: smudges })
-            } else {
-                None
-            }
-        })
-        .collect()
+        Self::find_reflections(&self.tiles, Mirror::Horizontal)
     }
     
     fn find_vertical(&self) -> Vec<Reflection> {
+        // columns are the rows of the transposed pattern
+        Self::find_reflections(&self.tiles.transpose(), Mirror::Vertical)
+    }
+
+    fn find_reflections(tiles: &Grid<Tile>, mirror: fn(usize) -> Mirror) -> Vec<Reflection> {
 
-        self.tiles.columns_iter().enumerate().filter_map(|(i, _)| {
-            let col = self.tiles.as_columns();
-            let (top, bottom) = col.split_at(i);
-            let comp = bottom.iter().zip(top.iter().rev()).collect::<Vec<_>>();
+        let rows = tiles.rows_iter().collect::<Vec<_>>();
+        (0..rows.len()).filter_map(|i| {
+            let (left, right) = rows.split_at(i);
+            let comp = right.iter().zip(left.iter().rev()).collect::<Vec<_>>();
             let smudges = comp.iter()
             .flat_map(|(rhs, lhs)| {
                 rhs.iter().zip(lhs.iter())
@@ -159,7 +159,7 @@ impl Pattern {
             });
 
             if !comp.is_empty() {
-                Some(Reflection{ mirror: Mirror::Vertical(i), smudge_count: smudges })
+                Some(Reflection{ mirror: mirror(i), smudge_count: smudges })
             } else {
                 None
             }
@@ -174,13 +174,29 @@ struct Reflection {
 }
 
 pub fn cal_reflection_code(input: &str) -> Result<usize> {
-    let patterns = Pattern::parse(input);
+    let patterns = Pattern::parse(input)?;
     let code = patterns.iter().map(|p| p.find_clean().value()).sum();
     Ok(code)
 }
 
 pub fn cal_reflection_code2(input: &str) -> Result<usize> {
-    let patterns = Pattern::parse(input);
+    let patterns = Pattern::parse(input)?;
     let code = patterns.iter().map(|p| p.find_smudge().value()).sum();
     Ok(code)
 }
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_reflection_code(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_reflection_code2(input)?.into())
+    }
+}
@@ -0,0 +1,252 @@
+use core::panic;
+use std::{cmp::Ordering, fmt::Debug};
+
+use crate::{geometry::Direction, grid::{Cell, Grid}, prelude::*, visual::{self, Frame}};
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[allow(dead_code)]
+const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+#[test]
+fn test_total_load() {
+    assert_eq!(136, cal_total_load(INPUT).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_total_load);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day14::default());
+}
+
+#[test]
+fn test_total_load2() {
+    assert_eq!(64, cal_load_after(INPUT, 1_000_000_000).unwrap());
+}
+
+// rolls every rock up one cell at a time until nothing moves anymore
+#[cfg(test)]
+fn naive_load(input: &str) -> usize {
+    let mut rows = input.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+    let mut moved = true;
+    while moved {
+        moved = false;
+        for row in 1..rows.len() {
+            for col in 0..rows[row].len() {
+                if rows[row][col] == 'O' && rows[row - 1][col] == '.' {
+                    rows[row][col] = '.';
+                    rows[row - 1][col] = 'O';
+                    moved = true;
+                }
+            }
+        }
+    }
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| row.iter().filter(|&&c| c == 'O').count() * (rows.len() - i))
+        .sum()
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_tilt(input in "[.O#]{6}(\n[.O#]{6}){0,5}") {
+        prop_assert_eq!(cal_total_load(&input).unwrap(), naive_load(&input));
+
+        let platform = Platrom::parse(&input).unwrap();
+        let rocks = |p: &Platrom| p.platform.iter().filter(|(_, e)| **e == Element::RoundRock).count();
+        for dir in Direction::ALL {
+            let mut tilted = platform.clone();
+            tilted.tilt(dir);
+            prop_assert_eq!(rocks(&tilted), rocks(&platform));
+            let mut twice = tilted.clone();
+            twice.tilt(dir);
+            prop_assert_eq!(twice, tilted);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Element {
+    Empty,
+    RoundRock,
+    FixRock,
+}
+
+impl Cell for Element {
+    const EXPECTED: &'static str = "'.', 'O' or '#'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Element::Empty),
+            'O' => Some(Element::RoundRock),
+            '#' => Some(Element::FixRock),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Element::Empty => '.',
+            Element::RoundRock => 'O',
+            Element::FixRock => '#',
+        }
+    }
+}
+
+/// Round rocks roll to the start of a row or column when tilting up or left
+fn roll_order(direction: Direction, a: &Element, b: &Element) -> Ordering {
+    match (direction, a, b) {
+        (_, Element::FixRock, _) => panic!("Canbot sort on fixed rock"),
+        (_, _, Element::FixRock) => panic!("Canbot sort on fixed rock"),
+        (_, a, b) if a == b => Ordering::Equal,
+        (Direction::Up, Element::RoundRock, _) => Ordering::Less,
+        (Direction::Up, _, Element::RoundRock) => Ordering::Greater,
+        (Direction::Down, Element::RoundRock, _) => Ordering::Greater,
+        (Direction::Down, _, Element::RoundRock) => Ordering::Less,
+        (Direction::Left, Element::RoundRock, _) => Ordering::Less,
+        (Direction::Left, _, Element::RoundRock) => Ordering::Greater,
+        (Direction::Right, Element::RoundRock, _) => Ordering::Greater,
+        (Direction::Right, _, Element::RoundRock) => Ordering::Less,
+        _ => panic!("Invalid sort: {:?} {:?} {:?}", direction, a, b),
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct Platrom {
+    platform: Grid<Element>,
+}
+
+impl Platrom {
+    fn tilt(&mut self, direction: Direction) {
+        // up and down tilt the columns, which are the rows of the transposed platform
+        let platform = if direction.is_horizontal() {
+            self.platform.clone()
+        } else {
+            self.platform.transpose()
+        };
+        let new_cols = platform
+            .rows_iter()
+            .map(|col| {
+                let mut new_col = col
+                    .split(|&e| e == Element::FixRock)
+                    .map(|rollables| rollables.to_vec())
+                    .flat_map(|mut rollables| {
+                        rollables.sort_by(|a, b| roll_order(direction, a, b));
+                        rollables.push(Element::FixRock);
+                        rollables
+                    })
+                    .collect::<Vec<_>>();
+                //last # is not needed
+                new_col.pop();
+                new_col
+            })
+            .collect::<Vec<_>>();
+
+        let platform = Grid::from_rows(new_cols).expect("tilting keeps the size");
+        if direction.is_horizontal() {
+            self.platform = platform;
+        } else {
+            self.platform = platform.transpose();
+        }
+    }
+
+    /// One spin cycle, a frame after every tilt
+    fn cycle(&mut self, round: usize) {
+        for direction in [Direction::Up, Direction::Left, Direction::Down, Direction::Right] {
+            self.tilt(direction);
+            visual::frame(|| Frame::grid(format!("cycle {} tilted {:?}", round, direction), &self.platform));
+        }
+    }
+
+    fn cal_load(&self) -> usize {
+        let row_len = self.platform.rows();
+        self.platform
+            .rows_iter()
+            .enumerate()
+            .map(|(col_iter, row)| {
+                row.iter().filter(|&&e| e == Element::RoundRock).count() * (row_len - col_iter)
+            })
+            .sum()
+    }
+}
+
+impl Platrom {
+    fn parse(input: &str) -> Result<Self> {
+        let platform = Grid::parse(&Source::new(14, input), input)?;
+        Ok(Self { platform })
+    }
+}
+
+impl Debug for Platrom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.platform)
+    }
+}
+
+pub fn cal_total_load(input: &str) -> Result<usize> {
+    let mut platform = Platrom::parse(input)?;
+    visual::frame(|| Frame::grid("before tilting", &platform.platform));
+    platform.tilt(Direction::Up);
+    visual::frame(|| Frame::grid("tilted Up", &platform.platform));
+    let load = platform.cal_load();
+    Ok(load)
+}
+
+pub fn cal_load_after(input: &str, rounds: usize) -> Result<usize> {
+    let mut platform = Platrom::parse(input)?;
+    let mut seen = Vec::new();
+    for i in 0..=rounds {
+        if let Some(pos) = seen.iter().position(|seen| seen == &platform) {
+            let loop_len = i - pos;
+            let remaining = (rounds - pos) % loop_len;
+            for round in rounds - remaining..rounds {
+                platform.cycle(round);
+            }
+            break;
+        }
+        seen.push(platform.clone());
+        platform.cycle(i);
+    }
+    let load = platform.cal_load();
+    Ok(load)
+}
+
+pub struct Day14 {
+    pub cycles: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            cycles: 1_000_000_000,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_total_load(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_load_after(input, self.cycles)?.into())
+    }
+}
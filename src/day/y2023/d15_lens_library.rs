@@ -8,11 +8,28 @@ fn test_cal_hash_sum() {
     assert_eq!(1320, cal_hash_sum(INPUT).unwrap());
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_hash_sum);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day15);
+}
+
 #[test]
 fn test_focus_power() {
     assert_eq!(145, cal_focus_power(INPUT).unwrap());
 }
 
+#[test]
+fn test_invalid_steps() {
+    let err = cal_focus_power("rn=1,cm,qp=3").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 1, column: 6, .. })));
+    assert!(matches!(cal_focus_power("rn=x"), Err(AocError::Parse(ParseError { line: 1, column: 4, .. }))));
+}
+
 #[derive(Clone)]
 enum Operation {
     Remove,
@@ -27,24 +44,24 @@ struct Step {
 }
 
 impl Step {
-    fn parse(input: &str) -> Vec<Self> {
+    fn parse(input: &str) -> Result<Vec<Self>> {
+        let source = Source::new(15, input);
         input
             .split(',')
             .map(|s| {
-                if s.contains('-') {
-                    let label = s.split('-').next().unwrap();
-                    Self {
+                if let Some((label, _)) = s.split_once('-') {
+                    Ok(Self {
                         input: s.to_owned(),
                         label: label.to_owned(),
                         operation: Operation::Remove,
-                    }
+                    })
                 } else {
-                    let (label, focal) = s.split_once('=').unwrap();
-                    Self {
+                    let (label, focal) = source.split_once(s, "=")?;
+                    Ok(Self {
                         input: s.to_owned(),
                         label: label.to_owned(),
-                        operation: Operation::FocalLen(focal.parse().unwrap()),
-                    }
+                        operation: Operation::FocalLen(source.num(focal)?),
+                    })
                 }
             })
             .collect()
@@ -75,13 +92,13 @@ impl Step {
 }
 
 pub fn cal_hash_sum(input: &str) -> Result<usize> {
-    let steps = Step::parse(input);
+    let steps = Step::parse(input)?;
     let sum = steps.iter().map(|s| s.full_hash() as usize).sum();
     Ok(sum)
 }
 
 pub fn cal_focus_power(input: &str) -> Result<usize> {
-    let steps = Step::parse(input);
+    let steps = Step::parse(input)?;
     //+1 because 0..256
     let mut boxes: Vec<Vec<Step>> = vec![Vec::new(); u8::MAX as usize + 1];
     for step in steps {
@@ -120,3 +137,19 @@ pub fn cal_focus_power(input: &str) -> Result<usize> {
         .sum();
     Ok(result)
 }
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_hash_sum(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_focus_power(input)?.into())
+    }
+}
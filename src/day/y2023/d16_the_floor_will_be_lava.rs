@@ -0,0 +1,298 @@
+use crate::{geometry::{Direction, Pos}, grid::{Cell, Grid}, prelude::*, visual::{self, Frame}};
+use std::{fmt::Debug, collections::HashSet};
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[allow(dead_code)]
+const INPUT: &str = ".|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....";
+
+#[test]
+fn test_energized_tiles() {
+    assert_eq!(46, cal_energized_tiles(INPUT).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_energized_tiles);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day16);
+}
+
+#[test]
+fn test_max_energized_tiles() {
+    assert_eq!(51, cal_max_energized_tiles(INPUT).unwrap());
+}
+
+#[test]
+fn test_invalid_floor() {
+    let err = cal_energized_tiles(".|.\n.#.").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 2, .. })));
+}
+
+// the beam on plain (row, col) deltas like before the shared geometry, every start from `starts`
+#[cfg(test)]
+fn naive_energized(input: &str, starts: &[(isize, isize, isize, isize)]) -> usize {
+    let floor = input.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+    let (rows, cols) = (floor.len() as isize, floor[0].len() as isize);
+    starts
+        .iter()
+        .map(|&start| {
+            let mut seen = HashSet::new();
+            let mut beams = vec![start];
+            while let Some((row, col, d_row, d_col)) = beams.pop() {
+                if row < 0 || col < 0 || row >= rows || col >= cols || !seen.insert((row, col, d_row, d_col)) {
+                    continue;
+                }
+                let dirs = match floor[row as usize][col as usize] {
+                    '/' => vec![(-d_col, -d_row)],
+                    '\\' => vec![(d_col, d_row)],
+                    '|' if d_col != 0 => vec![(-1, 0), (1, 0)],
+                    '-' if d_row != 0 => vec![(0, -1), (0, 1)],
+                    _ => vec![(d_row, d_col)],
+                };
+                beams.extend(dirs.into_iter().map(|(d_row, d_col)| (row + d_row, col + d_col, d_row, d_col)));
+            }
+            seen.iter().map(|&(row, col, _, _)| (row, col)).collect::<HashSet<_>>().len()
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_energized(input in "[./\\\\|-]{5}(\n[./\\\\|-]{5}){0,5}") {
+        prop_assert_eq!(cal_energized_tiles(&input).unwrap(), naive_energized(&input, &[(0, 0, 0, 1)]));
+
+        let (rows, cols) = (input.lines().count() as isize, 5);
+        let starts = (0..cols)
+            .flat_map(|col| [(0, col, 1, 0), (rows - 1, col, -1, 0)])
+            .chain((0..rows).flat_map(|row| [(row, 0, 0, 1), (row, cols - 1, 0, -1)]))
+            .collect::<Vec<_>>();
+        prop_assert_eq!(cal_max_energized_tiles(&input).unwrap(), naive_energized(&input, &starts));
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Tile {
+    Empty,
+    ForwardMirror,
+    BackwardMirror,
+    VerticalSplitter,
+    HorizontalSplitter,
+}
+
+impl Cell for Tile {
+    const EXPECTED: &'static str = "one of . / \\ | -";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Tile::Empty),
+            '/' => Some(Tile::ForwardMirror),
+            '\\' => Some(Tile::BackwardMirror),
+            '|' => Some(Tile::VerticalSplitter),
+            '-' => Some(Tile::HorizontalSplitter),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::ForwardMirror => '/',
+            Tile::BackwardMirror => '\\',
+            Tile::VerticalSplitter => '|',
+            Tile::HorizontalSplitter => '-',
+        }
+    }
+}
+
+impl Debug for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl Tile {
+    fn walk(&self, dir: Direction) -> Vec<Direction> {
+        match (self, dir) {
+            (Tile::Empty, dir) => vec![dir],
+            // /
+            (Tile::ForwardMirror, Direction::Up) => vec![Direction::Right],
+            (Tile::ForwardMirror, Direction::Down) => vec![Direction::Left],
+            (Tile::ForwardMirror, Direction::Left) => vec![Direction::Down],
+            (Tile::ForwardMirror, Direction::Right) => vec![Direction::Up],
+            // \
+            (Tile::BackwardMirror, Direction::Up) => vec![Direction::Left],
+            (Tile::BackwardMirror, Direction::Down) => vec![Direction::Right],
+            (Tile::BackwardMirror, Direction::Left) => vec![Direction::Up],
+            (Tile::BackwardMirror, Direction::Right) => vec![Direction::Down],
+            // |
+            (Tile::VerticalSplitter, Direction::Left) => vec![Direction::Up, Direction::Down],
+            (Tile::VerticalSplitter, Direction::Right) => vec![Direction::Up, Direction::Down],
+            (Tile::VerticalSplitter, dir) => vec![dir],
+            // -
+            (Tile::HorizontalSplitter, Direction::Up) => vec![Direction::Left, Direction::Right],
+            (Tile::HorizontalSplitter, Direction::Down) => vec![Direction::Left, Direction::Right],
+            (Tile::HorizontalSplitter, dir) => vec![dir],
+        }
+    }
+}
+
+type Energized = bool;
+
+#[derive(Clone)]
+struct Floor {
+    floor: Grid<(Tile, Energized, HashSet<Direction>)>,
+}
+
+impl Floor {
+    fn parse(input: &str) -> Result<Self> {
+        let tiles: Grid<Tile> = Grid::parse(&Source::new(16, input), input)?;
+        let floor = tiles.map(|tile| (*tile, false, HashSet::new()));
+        Ok(Self { floor })
+    }
+
+    fn walk_start(&mut self, pos: Pos, dir: Direction) {
+        let mut queue = vec![(pos, dir)];
+        while let Some((pos, dir)) = queue.pop() {
+            let mut new_dirs = self.walk_step(pos, dir);
+            queue.append(&mut new_dirs);
+        }
+    }
+
+    fn walk_step(&mut self, pos: Pos, dir: Direction) -> Vec<(Pos, Direction)> {
+        let (tile, energized, walked_dirs) = &mut self.floor[pos];
+        if walked_dirs.contains(&dir) {
+            //already walked this way
+            return Vec::new();
+        }
+
+        walked_dirs.insert(dir);
+        *energized = true;
+
+        let tile = *tile;
+        visual::frame(|| Frame::new(format!("{:?} going {:?}", pos, dir), self.render(pos)));
+        tile.walk(dir)
+            .into_iter()
+            .filter_map(|dir| {
+                //positions outside are the end of the floor
+                self.floor.step(pos, dir).map(|pos| (pos, dir))
+            })
+            .collect()
+    }
+
+    /// Energized tiles as #, the beam at `at` as @
+    fn render(&self, at: Pos) -> String {
+        self.floor
+            .rows_iter()
+            .enumerate()
+            .map(|(row, tiles)| {
+                tiles
+                    .iter()
+                    .enumerate()
+                    .map(|(col, (tile, energized, _))| match (Pos::new(row, col) == at, energized) {
+                        (true, _) => '@',
+                        (false, true) => '#',
+                        (false, false) => tile.to_char(),
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn get_energized(&self) -> usize {
+        self.floor
+            .iter()
+            .filter(|(_, (_, energized, _))| *energized)
+            .count()
+    }
+
+    fn find_max(&self) -> usize {
+        let row_cnt = self.floor.rows();
+        let col_cnt = self.floor.cols();
+
+        let mut first_row = (0..col_cnt)
+        .map(|i| {
+            (Pos::new(0, i), Direction::Down)
+        })
+        .collect::<Vec<_>>();
+
+        let mut last_row = (0..col_cnt)
+        .map(|i| {
+            (Pos::new(row_cnt-1, i), Direction::Up)
+        })
+        .collect::<Vec<_>>();
+
+        let mut first_col = (0..row_cnt)
+        .map(|i| {
+            (Pos::new(i, 0), Direction::Right)
+        })
+        .collect::<Vec<_>>();
+
+        let mut last_col = (0..row_cnt)
+        .map(|i| {
+            (Pos::new(i, col_cnt-1), Direction::Left)
+        })
+        .collect::<Vec<_>>();
+
+
+        let mut start = Vec::new();
+        start.append(&mut first_row);
+        start.append(&mut last_row);
+        start.append(&mut first_col);
+        start.append(&mut last_col);
+        
+        let max = start.into_iter()
+        .map(|(pos, dir)| {
+            let mut floor = self.clone();
+            floor.walk_start(pos, dir);
+            floor.get_energized()
+        })
+        .max();
+
+        max.unwrap()
+    }
+}
+
+pub fn cal_energized_tiles(input: &str) -> Result<usize> {
+    let mut floor = Floor::parse(input)?;
+    floor.walk_start(Pos::new(0, 0), Direction::Right);
+    let energized = floor.get_energized();
+    Ok(energized)
+}
+
+pub fn cal_max_energized_tiles(input: &str) -> Result<usize> {
+    let floor = Floor::parse(input)?;
+    let max = floor.find_max();
+    Ok(max)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_energized_tiles(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_max_energized_tiles(input)?.into())
+    }
+}
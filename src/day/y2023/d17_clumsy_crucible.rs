@@ -0,0 +1,77 @@
+//TODO Implement D17
+#![allow(dead_code, unused)]
+
+use crate::{grid::{Cell, Grid}, prelude::*};
+
+#[allow(dead_code)]
+const INPUT: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+#[test]
+fn test_min_heat_loss() {
+    assert_eq!(102, cal_minimum_heat_loss(INPUT).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    // solving is still todo, so only compare the parsed map
+    crate::input::check_variants(INPUT, |input| Map::parse(input).map(|map| map.map));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HeatLoss(usize);
+
+impl Cell for HeatLoss {
+    const EXPECTED: &'static str = "a digit";
+
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|loss| HeatLoss(loss as usize))
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(self.0 as u32, 10).unwrap_or('?')
+    }
+}
+
+struct Map {
+    map: Grid<HeatLoss>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self> {
+        let map = Grid::parse(&Source::new(17, input), input)?;
+        Ok(Self { map })
+    }
+}
+
+pub fn cal_minimum_heat_loss(input: &str) -> Result<usize> {
+    let map = Map::parse(input)?;
+    todo!("idk how to find the minimum... iterating over all possibilities cant be the solution")
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_minimum_heat_loss(input)?.into())
+    }
+
+    fn status(&self, _part: Part) -> Status {
+        Status::Todo
+    }
+}
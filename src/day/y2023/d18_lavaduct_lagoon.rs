@@ -0,0 +1,195 @@
+//TODO Implement D18
+#![allow(unused)]
+
+use std::{collections::HashMap, fmt::Debug};
+
+use crate::{geometry::{Direction, IPos, Pos}, grid::Grid, prelude::*};
+
+#[allow(dead_code)]
+const INPUT: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+#[test]
+fn test_trench_colume() {
+    assert_eq!(62, cal_trench_volume(INPUT).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    // digging the interior is still todo, so only compare the parsed plan
+    crate::input::check_variants(INPUT, DigStep::parse);
+}
+
+#[test]
+fn test_dig_up_and_left() {
+    let steps = DigStep::parse("U 2 (#70c710)\nL 3 (#0dc571)\nD 2 (#5713f0)\nR 3 (#d2c081)").unwrap();
+    let trench = Trench::dig(&steps);
+    assert_eq!(format!("{:?}", trench), "####\n#..#\n####\n");
+    assert_eq!(trench.size(), 10);
+}
+
+#[test]
+fn test_invalid_dig_plan() {
+    let err = cal_trench_volume("R 6 (#70c710)\nX 5 (#0dc571)").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 1, .. })));
+    assert!(cal_trench_volume("R six (#70c710)").is_err());
+    assert!(cal_trench_volume("R 6").is_err());
+}
+
+fn parse_direction(source: &Source, value: &str) -> Result<Direction> {
+    match value {
+        "R" => Ok(Direction::Right),
+        "L" => Ok(Direction::Left),
+        "U" => Ok(Direction::Up),
+        "D" => Ok(Direction::Down),
+        _ => Err(source.error(value, "R, L, U or D")),
+    }
+}
+
+type Color = String;
+
+#[derive(Debug, PartialEq, Eq)]
+struct DigStep {
+    direction: Direction,
+    steps: usize,
+    color: Color,
+}
+
+impl DigStep {
+    fn parse(input: &str) -> Result<Vec<Self>> {
+        let source = Source::new(18, input);
+        input
+            .lines()
+            .map(|line| {
+                let (direction, rest) = source.split_once(line, " ")?;
+                let (steps, color) = source.split_once(rest, " ")?;
+
+                Ok(Self {
+                    direction: parse_direction(&source, direction)?,
+                    steps: source.num(steps)?,
+                    color: color.to_owned(),
+                })
+            })
+            .collect()
+    }
+}
+
+type Digged = bool;
+struct Trench {
+    trench: Grid<(Digged, Option<Color>)>,
+}
+
+impl Trench {
+    fn dig(steps: &[DigStep]) -> Self {
+        // dig using a map, so no final size is needed
+        // digging may go left of or above the start
+        let mut pos = IPos::new(0, 0);
+        let mut trench_map: HashMap<IPos, Color> = HashMap::new();
+        for step in steps {
+            for _ in 0..step.steps {
+                pos = pos.step(step.direction);
+                trench_map.insert(pos, step.color.clone());
+            }
+        }
+
+        // find size, shifted so the top left is (0, 0)
+        let min_row = trench_map.keys().map(|pos| pos.row).min().unwrap_or_default();
+        let max_row = trench_map.keys().map(|pos| pos.row).max().unwrap_or_default();
+        let min_col = trench_map.keys().map(|pos| pos.col).min().unwrap_or_default();
+        let max_col = trench_map.keys().map(|pos| pos.col).max().unwrap_or_default();
+
+        let rows = (max_row - min_row) as usize + 1;
+        let columns = (max_col - min_col) as usize + 1;
+        let mut trench = Grid::new(rows, columns, (false, None));
+
+        // fill with values from map
+        for (pos, color) in trench_map.into_iter() {
+            let pos = Pos::new((pos.row - min_row) as usize, (pos.col - min_col) as usize);
+            trench[pos] = (true, Some(color));
+        }
+
+        Self { trench }
+    }
+
+    fn dig_interior(&mut self) {
+        todo!("idk how to find inner trench. visit neighbor positions seems fittable?");
+        // for row in 0..self.trench.column_len() {
+        //     let mut inner = false;
+        //     let mut on_trench = false;
+        //     for col in 0..self.trench.row_len() {
+        //         let (digged, _) = self.trench.get_mut(row, col).unwrap();
+        //         if *digged {
+        //             on_trench = true;
+        //         }
+        //         if !on_trench {}
+        //         if inner {
+        //             *digged = true;
+        //         }
+        //     }
+        // }
+        // todo!()
+    }
+
+    fn size(&self) -> usize {
+        self.trench
+            .iter()
+            .filter(|(_, (digged, _))| *digged)
+            .count()
+    }
+}
+
+impl Debug for Trench {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .trench
+            .rows_iter()
+            .map(|row| {
+                row.iter().map(|(digged, _)| if *digged { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        for row in rows {
+            writeln!(f, "{}", row)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn cal_trench_volume(input: &str) -> Result<usize> {
+    let steps = DigStep::parse(input)?;
+    let mut trench = Trench::dig(&steps);
+    trench.dig_interior();
+
+    let size = trench.size();
+    Ok(size)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_trench_volume(input)?.into())
+    }
+
+    fn status(&self, _part: Part) -> Status {
+        Status::Todo
+    }
+}
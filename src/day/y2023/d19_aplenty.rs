@@ -26,12 +26,32 @@ fn test_sum_accepted() {
     assert_eq!(19114, cal_sum_accepted(INPUT).unwrap());
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_sum_accepted);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day19);
+}
+
 #[test]
 fn test_sum_accepted2() {
     todo!("too slow to test");
     // assert_eq!(167409079868000, cal_all_possibilities(INPUT).unwrap());
 }
 
+#[test]
+fn test_invalid_workflow() {
+    let err = cal_sum_accepted("in{y<2006:A,R}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 1, column: 4, .. })));
+    let err = cal_sum_accepted("in{x<2006:A,R}\n\n{x=1,m=2,a=3,s=z}").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 3, column: 16, .. })));
+    assert!(cal_sum_accepted("in{x<2006:A,R}").is_err());
+    assert!(cal_sum_accepted("in{x=2006:A,R}\n\n{x=1,m=2,a=3,s=4}").is_err());
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 struct Part {
     component: [usize; 4],
@@ -53,22 +73,22 @@ impl Part {
     }
 }
 
-impl From<&str> for Part {
-    fn from(input: &str) -> Self {
-        let input = input.replace(['{', '}'], "");
-        let component = input
+impl Part {
+    fn parse(source: &Source, input: &str) -> Result<Self> {
+        let ratings = input
+            .strip_prefix('{')
+            .and_then(|input| input.strip_suffix('}'))
+            .ok_or_else(|| source.error(input, "{x=..,m=..,a=..,s=..}"))?;
+        let values = ratings
             .split(',')
             .map(|element| {
-                let (_, value) = element.split_once('=').unwrap();
-                value.parse().unwrap()
+                let (_, value) = source.split_once(element, "=")?;
+                source.num(value)
             })
-            .enumerate()
-            .fold([0; 4], |mut components, (index, value)| {
-                components[index] = value;
-                components
-            });
+            .collect::<Result<Vec<usize>>>()?;
+        let component = values.try_into().map_err(|_| source.error(ratings, "4 ratings"))?;
 
-        Self { component }
+        Ok(Self { component })
     }
 }
 
@@ -80,14 +100,14 @@ enum Category {
     Shiny,
 }
 
-impl From<&str> for Category {
-    fn from(input: &str) -> Self {
+impl Category {
+    fn parse(source: &Source, input: &str) -> Result<Self> {
         match input {
-            "x" => Category::XtreamCool,
-            "m" => Category::Musical,
-            "a" => Category::Aerodynamic,
-            "s" => Category::Shiny,
-            _ => panic!("unknown category {}", input),
+            "x" => Ok(Category::XtreamCool),
+            "m" => Ok(Category::Musical),
+            "a" => Ok(Category::Aerodynamic),
+            "s" => Ok(Category::Shiny),
+            _ => Err(source.error(input, "x, m, a or s")),
         }
     }
 }
@@ -143,25 +163,25 @@ impl Rule {
     }
 }
 
-impl From<&str> for Rule {
-    fn from(input: &str) -> Self {
+impl Rule {
+    fn parse(source: &Source, input: &str) -> Result<Self> {
         if let Some((check, result)) = input.split_once(':') {
             let result = RuleResult::from(result);
             let bigger_then = check.contains('>');
-            let splitter = if bigger_then { '>' } else { '<' };
-            let (category, value) = check.split_once(splitter).unwrap();
-            let category = Category::from(category);
-            let value = value.parse().unwrap();
+            let splitter = if bigger_then { ">" } else { "<" };
+            let (category, value) = source.split_once(check, splitter)?;
+            let category = Category::parse(source, category)?;
+            let value = source.num(value)?;
 
-            Self {
+            Ok(Self {
                 result,
                 check: Some((category, bigger_then, value)),
-            }
+            })
         } else {
-            Self {
+            Ok(Self {
                 check: None,
                 result: RuleResult::from(input),
-            }
+            })
         }
     }
 }
@@ -174,21 +194,30 @@ struct Workflow {
 }
 
 impl Workflow {
-    fn parse(input: &str) -> Self {
-        let (rules, parts) = input.split_once("\n\n").unwrap();
+    fn parse(input: &str) -> Result<Self> {
+        let source = Source::new(19, input);
+        let (rules, parts) = source.split_once(input, "\n\n")?;
         let rules = rules
             .lines()
             .map(|line| {
-                let (name, workflow) = line.split_once('{').unwrap();
-                let workflow = workflow.replace('}', "");
-                let rules = workflow.split(',').map(Rule::from).collect();
-                (name.to_owned(), rules)
+                let (name, workflow) = source.split_once(line, "{")?;
+                let workflow = workflow
+                    .strip_suffix('}')
+                    .ok_or_else(|| source.error(workflow, "rules ending with '}'"))?;
+                let rules = workflow
+                    .split(',')
+                    .map(|rule| Rule::parse(&source, rule))
+                    .collect::<Result<_>>()?;
+                Ok((name.to_owned(), rules))
             })
-            .collect();
+            .collect::<Result<_>>()?;
 
-        let parts = parts.lines().map(Part::from).collect();
+        let parts = parts
+            .lines()
+            .map(|line| Part::parse(&source, line))
+            .collect::<Result<_>>()?;
 
-        Self { rules, parts }
+        Ok(Self { rules, parts })
     }
 
     fn get_accepted(&self) -> Vec<Part> {
@@ -209,7 +238,7 @@ impl Workflow {
         accepted
     }
 
-    fn get_combinations(&self) -> usize {
+    fn get_combinations(&self) -> Result<usize> {
         let mut accepted = 0;
         let all_parts = (1..=4000).flat_map(|x| {
             (1..=4000).flat_map(move |m| {
@@ -222,7 +251,7 @@ impl Workflow {
             })
         });
         for part in all_parts {
-            println!("{:?}", part);
+            crate::cancel::check()?;
             let mut rules = self.rules.get("in").unwrap();
             loop {
                 match Rule::apply_all(rules, &part) {
@@ -235,12 +264,12 @@ impl Workflow {
                 }
             }
         }
-        accepted
+        Ok(accepted)
     }
 }
 
 pub fn cal_sum_accepted(input: &str) -> Result<usize> {
-    let workflow = Workflow::parse(input);
+    let workflow = Workflow::parse(input)?;
     let accepted = workflow.get_accepted();
     let sum = accepted.into_iter().map(|p| p.sum()).sum();
     Ok(sum)
@@ -248,7 +277,30 @@ pub fn cal_sum_accepted(input: &str) -> Result<usize> {
 
 #[allow(dead_code)]
 pub fn cal_all_possibilities(input: &str) -> Result<usize> {
-    let workflow = Workflow::parse(input);
-    let comb = workflow.get_combinations();
+    let workflow = Workflow::parse(input)?;
+    let comb = workflow.get_combinations()?;
     Ok(comb)
 }
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_sum_accepted(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_all_possibilities(input)?.into())
+    }
+
+    fn status(&self, part: crate::day::Part) -> Status {
+        match part {
+            crate::day::Part::One => Status::Done,
+            crate::day::Part::Two => Status::Slow,
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use std::{collections::VecDeque, io::BufRead};
+
+use crate::prelude::*;
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[test]
+fn test_trebuchet() {
+    let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    assert_eq!(142, cal_trebuchet(input).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+    crate::input::check_variants(input, cal_trebuchet);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day1);
+}
+
+#[test]
+fn test_trebuchet_2() {
+    let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    assert_eq!(281, cal_trebuchet_str(input).unwrap());
+}
+
+#[test]
+fn test_overlapping_words() {
+    let scanner = Scanner::new(&Vocabulary::english());
+    let digits = |line| scanner.matches(line).map(|(_, digit)| digit).collect::<Vec<_>>();
+    assert_eq!(digits("eightwo"), vec![8, 2]);
+    assert_eq!(digits("xtwone3four"), vec![2, 1, 3, 4]);
+    assert_eq!(digits("oneight"), vec![1, 8]);
+    assert_eq!(scanner.matches("zoneight234").next(), Some((1, 1)));
+}
+
+#[test]
+fn test_vocabularies() {
+    let german = Scanner::new(&Vocabulary::german());
+    assert_eq!(german.first_last("xfünfzweiundsiebzig").unwrap(), (5, 2));
+    assert_eq!(german.first_last("achteins").unwrap(), (8, 1));
+
+    let digits = Scanner::new(&Vocabulary::digits());
+    assert_eq!(digits.first_last("one2three").unwrap(), (2, 2));
+    assert!(digits.first_last("onetwo").is_err());
+    // zero is a digit, but not a word
+    assert_eq!(digits.first_last("a0b").unwrap(), (0, 0));
+    assert_eq!(cal_trebuchet("a0b\n1zero").unwrap(), 11);
+    assert_eq!(cal_trebuchet_str("zero1\n0two").unwrap(), 11 + 2);
+
+    let words = Vocabulary::new([("uno", 1), ("dos", 2), ("tres", 3)]).unwrap();
+    let spanish = Scanner::new(&words.and(Vocabulary::digits()));
+    assert_eq!(cal_calibration("unodostres\nx9tresx", &spanish).unwrap(), 13 + 93);
+
+    assert!(Vocabulary::new([("", 1)]).is_err());
+    assert!(Vocabulary::new([("ten", 10)]).is_err());
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn test_scanner_finds_like_str_find(line in "[a-z0-9]{0,30}") {
+        let vocabulary = Vocabulary::english();
+        let scanner = Scanner::new(&vocabulary);
+        let first = vocabulary.words.iter().filter_map(|(word, digit)| line.find(word.as_str()).map(|pos| (pos, *digit))).min();
+        let last = vocabulary.words.iter().filter_map(|(word, digit)| line.rfind(word.as_str()).map(|pos| (pos, *digit))).max();
+        let found = scanner.matches(&line).collect::<Vec<_>>();
+        prop_assert_eq!(found.iter().min_by_key(|(start, _)| *start).copied(), first);
+        prop_assert_eq!(found.iter().max_by_key(|(start, _)| *start).copied(), last);
+    }
+}
+
+#[test]
+fn test_calibrate_reader() {
+    let input = b"1abc2\r\nno digits\n\ntreb7uchet\nxx\xffx3";
+    // a tiny buffer, so lines are split across reads
+    let reader = std::io::BufReader::with_capacity(4, &input[..]);
+    let mut failed = Vec::new();
+    let calibration = calibrate(reader, &Scanner::new(&Vocabulary::digits()), |failure| {
+        failed.push((failure.line, failure.text))
+    })
+    .unwrap();
+    assert_eq!(calibration.sum, 12 + 77 + 33);
+    assert_eq!(calibration.lines, 5);
+    assert_eq!(calibration.failed, 2);
+    assert_eq!(failed, vec![(2, "no digits".to_owned()), (3, "".to_owned())]);
+
+    let AocError::Parse(err) = cal_trebuchet("12\nnone\n3").unwrap_err() else {
+        panic!("not a parse error");
+    };
+    assert_eq!(err.line, 2);
+}
+
+pub fn cal_trebuchet(input: &str) -> Result<u32> {
+    cal_calibration(input, &Scanner::new(&Vocabulary::digits()))
+}
+
+pub fn cal_trebuchet_str(input: &str) -> Result<u32> {
+    cal_calibration(input, &Scanner::new(&Vocabulary::english()))
+}
+
+/// Sum of the first and last digit of every line, as a two digit number. The first line without a digit is an error.
+/// Sums past u32 are an error as well, [calibrate] has the whole u64.
+pub fn cal_calibration(input: &str, scanner: &Scanner) -> Result<u32> {
+    let mut first = None;
+    let calibration = calibrate(input.as_bytes(), scanner, |failure| {
+        first.get_or_insert(failure);
+    })?;
+    if let Some(failure) = first {
+        return Err(failure.into());
+    }
+    u32::try_from(calibration.sum).map_err(|_| format!("calibration {} doesn't fit into u32", calibration.sum).into())
+}
+
+/// Calibration of a whole document, lines without a digit are reported instead of stopping the sum
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calibration {
+    pub sum: u64,
+    pub lines: usize,
+    /// number of lines without a digit
+    pub failed: usize,
+}
+
+/// Calibrates one line at a time, so memory stays the same however long the document is.
+/// Lines without a digit are handed to `on_failure` as parse errors with their line number.
+/// Lines are scanned as bytes, so logs that aren't valid UTF-8 work as well.
+pub fn calibrate(mut reader: impl BufRead, scanner: &Scanner, mut on_failure: impl FnMut(ParseError)) -> Result<Calibration> {
+    let mut calibration = Calibration::default();
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        calibration.lines += 1;
+        let text = line.strip_suffix(b"\n").unwrap_or(&line);
+        let text = text.strip_suffix(b"\r").unwrap_or(text);
+        match scanner.first_last_bytes(text) {
+            Some((first, last)) => calibration.sum += first as u64 * 10 + last as u64,
+            None => {
+                calibration.failed += 1;
+                on_failure(ParseError {
+                    day: 1,
+                    line: calibration.lines,
+                    column: 1,
+                    text: String::from_utf8_lossy(text).into_owned(),
+                    expected: "a digit".to_owned(),
+                });
+            }
+        }
+    }
+    Ok(calibration)
+}
+
+/// The words that stand for a digit
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    words: Vec<(String, u8)>,
+}
+
+const ENGLISH: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const GERMAN: [&str; 9] = ["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"];
+
+impl Vocabulary {
+    /// Digits must be 0 to 9, empty words would match everywhere
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, u8)>) -> Result<Self> {
+        let words = words.into_iter().map(|(word, digit)| (word.into(), digit)).collect::<Vec<_>>();
+        if let Some((word, digit)) = words.iter().find(|(word, digit)| word.is_empty() || *digit > 9) {
+            return Err(format!("{:?} can't stand for {}", word, digit).into());
+        }
+        Ok(Self { words })
+    }
+
+    /// Only 0 to 9, like part 1
+    pub fn digits() -> Self {
+        Self {
+            words: (0..=9).map(|digit: u8| (digit.to_string(), digit)).collect(),
+        }
+    }
+
+    /// Digits and the english words, like part 2
+    pub fn english() -> Self {
+        Self::digits().and(Self::numbers(ENGLISH))
+    }
+
+    /// Digits and the german words
+    pub fn german() -> Self {
+        Self::digits().and(Self::numbers(GERMAN))
+    }
+
+    fn numbers(words: [&str; 9]) -> Self {
+        Self {
+            words: words.into_iter().zip(1..).map(|(word, digit)| (word.to_owned(), digit)).collect(),
+        }
+    }
+
+    /// Words of both, e.g. to read logs in two languages
+    pub fn and(mut self, other: Self) -> Self {
+        self.words.extend(other.words);
+        self
+    }
+}
+
+/// Aho-Corasick automaton of a vocabulary, finds all words of a line in a single pass over its bytes.
+/// Overlapping words are all found, "eightwo" is 8 and 2.
+pub struct Scanner {
+    /// next state for every state and byte, the failure links are already followed
+    next: Vec<[u32; 256]>,
+    /// words ending in a state as (length, digit), including the ones ending in its failure states
+    found: Vec<Vec<(usize, u8)>>,
+}
+
+impl Scanner {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        // trie of the words, state 0 is the root, which is no child so 0 means no transition for now
+        let mut next = vec![[0_u32; 256]];
+        let mut found = vec![Vec::new()];
+        for (word, digit) in &vocabulary.words {
+            let mut state = 0;
+            for &byte in word.as_bytes() {
+                if next[state][byte as usize] == 0 {
+                    next.push([0; 256]);
+                    found.push(Vec::new());
+                    next[state][byte as usize] = (next.len() - 1) as u32;
+                }
+                state = next[state][byte as usize] as usize;
+            }
+            found[state].push((word.len(), *digit));
+        }
+
+        // breadth first, so the failure state is always complete
+        let mut fail = vec![0; next.len()];
+        let mut queue = next[0].iter().filter(|&&child| child != 0).map(|&child| child as usize).collect::<VecDeque<_>>();
+        while let Some(state) = queue.pop_front() {
+            let inherited = found[fail[state]].clone();
+            found[state].extend(inherited);
+            let fallback = next[fail[state]];
+            for (child, fallback) in next[state].iter_mut().zip(fallback) {
+                if *child == 0 {
+                    *child = fallback;
+                } else {
+                    fail[*child as usize] = fallback as usize;
+                    queue.push_back(*child as usize);
+                }
+            }
+        }
+
+        Self { next, found }
+    }
+
+    /// All words of the line as (byte offset, digit), ordered by where they end
+    pub fn matches<'a>(&'a self, line: &'a str) -> impl Iterator<Item = (usize, u8)> + 'a {
+        self.matches_bytes(line.as_bytes())
+    }
+
+    fn matches_bytes<'a>(&'a self, line: &'a [u8]) -> impl Iterator<Item = (usize, u8)> + 'a {
+        line.iter()
+            .enumerate()
+            .scan(0, move |state, (pos, byte)| {
+                *state = self.next[*state][*byte as usize] as usize;
+                Some((pos, *state))
+            })
+            .flat_map(move |(pos, state)| self.found[state].iter().map(move |(len, digit)| (pos + 1 - len, *digit)))
+    }
+
+    /// The digits of the words starting first and last
+    pub fn first_last(&self, line: &str) -> Result<(u8, u8)> {
+        self.first_last_bytes(line.as_bytes())
+            .ok_or_else(|| format!("no number found in {:?}", line).into())
+    }
+
+    fn first_last_bytes(&self, line: &[u8]) -> Option<(u8, u8)> {
+        let mut first: Option<(usize, u8)> = None;
+        let mut last: Option<(usize, u8)> = None;
+        for (start, digit) in self.matches_bytes(line) {
+            if first.is_none_or(|(first, _)| start < first) {
+                first = Some((start, digit));
+            }
+            if last.is_none_or(|(last, _)| start > last) {
+                last = Some((start, digit));
+            }
+        }
+        Some((first?.1, last?.1))
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_trebuchet(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_trebuchet_str(input)?.into())
+    }
+}
@@ -15,6 +15,21 @@ fn test_pulses() {
     assert_eq!(cal_pulses(input).unwrap(), 32000000);
 }
 
+#[test]
+fn test_input_variants() {
+    let input = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+    crate::input::check_variants(input, cal_pulses);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day20);
+}
+
 #[test]
 fn test_pulses2() {
     let input = "broadcaster -> a
@@ -25,6 +40,13 @@ fn test_pulses2() {
     assert_eq!(cal_pulses(input).unwrap(), 11687500);
 }
 
+#[test]
+fn test_invalid_module() {
+    let err = cal_pulses("broadcaster -> a\n#a -> b").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 1, .. })));
+    assert!(matches!(cal_pulses("broadcaster a"), Err(AocError::Parse(ParseError { line: 1, .. }))));
+}
+
 enum ModuleTyp {
     Broadcast,
     FlipFlop,
@@ -74,8 +96,8 @@ impl Module {
         }
     }
 
-    fn parse(input: &str) -> (String, Module) {
-        let (typ, targets) = input.split_once(" -> ").unwrap();
+    fn parse(source: &Source, input: &str) -> Result<(String, Module)> {
+        let (typ, targets) = source.split_once(input, " -> ")?;
         let (typ, name) = match typ {
             "broadcaster" => {
                 let typ = ModuleTyp::Broadcast;
@@ -92,7 +114,7 @@ impl Module {
                     let name = name.to_owned();
                     (typ, name)
                 } else {
-                    panic!("Invalid module type: {}", typ)
+                    return Err(source.error(label, "broadcaster, %name or &name"));
                 }
             }
         };
@@ -107,7 +129,7 @@ impl Module {
             targets,
         };
 
-        (name, module)
+        Ok((name, module))
     }
 }
 
@@ -139,7 +161,7 @@ struct Machine {
 }
 
 impl Machine {
-    fn push_button(&mut self, pushes: usize) -> Option<usize> {
+    fn push_button(&mut self, pushes: usize) -> Result<Option<usize>> {
         let mut queue = Queue::new();
         // let mut seen = Vec::new();
         // let mut counts = Vec::new();
@@ -150,6 +172,7 @@ impl Machine {
             .map(|name| (name.clone(), Pulse::Low))
             .collect::<State>();
         for round in 0..pushes {
+            crate::cancel::check()?;
             // if let Some(start) = seen.iter().position(|seen| *seen == state) {
                 // println!("Loop detected at round {}", round);
                 // let loop_len = round - start;
@@ -185,17 +208,12 @@ impl Machine {
                 }
                 if let Some(module) = self.modules.get_mut(&name) {
                     module.send_pulse(pulse, &mut queue, &mut state);
-                } else if "rx" == name  {
-                    if pulse == Pulse::Low && rec_rx.is_none() {
-                        println!("RX LOW at round {}", round);
-                        rec_rx = Some(round);
-                    } else {
-                        // println!("RX HIGH at round {}", round);
-                    }
+                } else if "rx" == name && pulse == Pulse::Low && rec_rx.is_none() {
+                    rec_rx = Some(round);
                 }
             }
         }
-        rec_rx
+        Ok(rec_rx)
     }
 
     fn get_pulses(&self) -> (usize, usize) {
@@ -203,9 +221,13 @@ impl Machine {
     }
 }
 
-impl From<&str> for Machine {
-    fn from(input: &str) -> Self {
-        let mut modules = input.lines().map(Module::parse).collect::<HashMap<_, _>>();
+impl Machine {
+    fn parse(input: &str) -> Result<Self> {
+        let source = Source::new(20, input);
+        let mut modules = input
+            .lines()
+            .map(|line| Module::parse(&source, line))
+            .collect::<Result<HashMap<_, _>>>()?;
 
         let conjunctions = modules
             .iter()
@@ -220,25 +242,22 @@ impl From<&str> for Machine {
                 .map(|(name, _)| name.clone())
                 .collect::<Vec<_>>();
 
-            let con = modules.get_mut(&con_name).unwrap();
-            if let ModuleTyp::Conjunction(inputs) = &mut con.typ {
+            if let Some(Module { typ: ModuleTyp::Conjunction(inputs), .. }) = modules.get_mut(&con_name) {
                 *inputs = new_inputs;
-            } else {
-                panic!("Invalid module type");
             }
         }
 
-        Self {
+        Ok(Self {
             modules,
             low_pulses: 0,
             high_pulses: 0,
-        }
+        })
     }
 }
 
 pub fn cal_pulses(input: &str) -> Result<usize> {
-    let mut machine = Machine::from(input);
-    machine.push_button(1000);
+    let mut machine = Machine::parse(input)?;
+    machine.push_button(1000)?;
     let (lows, highs) = machine.get_pulses();
     Ok(lows * highs)
 }
@@ -246,7 +265,30 @@ pub fn cal_pulses(input: &str) -> Result<usize> {
 //TODO too slow
 #[allow(dead_code)]
 pub fn cal_rx_pushes(input: &str) -> Result<usize> {
-    let mut machine = Machine::from(input);
-    let rx = machine.push_button(1_000_000);
-    Ok(rx.unwrap())
+    let mut machine = Machine::parse(input)?;
+    let rx = machine.push_button(1_000_000)?;
+    rx.ok_or_else(|| "rx never got a low pulse".into())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_pulses(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_rx_pushes(input)?.into())
+    }
+
+    fn status(&self, part: Part) -> Status {
+        match part {
+            Part::One => Status::Done,
+            Part::Two => Status::Slow,
+        }
+    }
 }
@@ -0,0 +1,221 @@
+use std::collections::HashSet;
+
+use crate::{geometry::{Direction, IPos, Pos}, grid::{Cell, Grid}, prelude::*};
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[allow(dead_code)]
+const INPUT: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+#[test]
+fn test_plot_count() {
+    assert_eq!(cal_plot_count(INPUT, 6).unwrap(), 16);
+}
+
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, |input| cal_plot_count(input, 6));
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day21 { steps: 6, ..Default::default() });
+}
+
+#[test]
+#[allow(unreachable_code)]
+fn test_plot_count_extending() {
+    assert_eq!(cal_plot_count_extending(INPUT, 6).unwrap(), 16);
+    assert_eq!(cal_plot_count_extending(INPUT, 10).unwrap(), 50);
+    assert_eq!(cal_plot_count_extending(INPUT, 50).unwrap(), 1594);
+    todo!("takes too long");
+    assert_eq!(cal_plot_count_extending(INPUT, 100).unwrap(), 6536);
+    assert_eq!(cal_plot_count_extending(INPUT, 500).unwrap(), 167004);
+    assert_eq!(cal_plot_count_extending(INPUT, 1000).unwrap(), 668697);
+    assert_eq!(cal_plot_count_extending(INPUT, 5000).unwrap(), 16733044);
+}
+
+#[test]
+#[allow(clippy::identity_op)]
+fn test_modulo() {
+    assert_eq!(-1 % 10, -1);
+    assert_eq!((-1_isize).rem_euclid(10), 9);
+}
+
+// on (row, col) tuples like before the shared geometry. The grid is a checkerboard,
+// so a plot is reached in exactly `steps` if it is at most that far and the parity fits.
+// Only a walled in start can't be stepped off and back onto
+#[cfg(test)]
+fn naive_plot_count(input: &str, steps: usize, extending: bool) -> usize {
+    let map = input.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+    let (rows, cols) = (map.len() as isize, map[0].len() as isize);
+    let start = input.lines().enumerate().find_map(|(row, line)| line.find('S').map(|col| (row as isize, col as isize)));
+    let mut seen = start.into_iter().collect::<HashSet<_>>();
+    let mut front = seen.iter().copied().collect::<Vec<_>>();
+    let mut count = 0;
+    for step in 1..=steps {
+        front = front
+            .into_iter()
+            .flat_map(|(row, col)| [(row - 1, col), (row + 1, col), (row, col - 1), (row, col + 1)])
+            .filter(|&(row, col)| {
+                let inside = (0..rows).contains(&row) && (0..cols).contains(&col);
+                (extending || inside) && map[row.rem_euclid(rows) as usize][col.rem_euclid(cols) as usize] != '#'
+            })
+            .filter(|&pos| seen.insert(pos))
+            .collect();
+        if step % 2 == steps % 2 {
+            count += front.len();
+        }
+    }
+    let stuck = steps > 0 && seen.len() == 1;
+    if start.is_some() && steps.is_multiple_of(2) && !stuck {
+        count += 1;
+    }
+    count
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_plot_count(input in "[.#]{5}(\n[.#]{5}){0,5}", start in 0..36_usize, steps in 0..12_usize) {
+        // exactly one start
+        let mut input = input.into_bytes();
+        let cells = input.iter().enumerate().filter(|(_, c)| **c != b'\n').map(|(i, _)| i).collect::<Vec<_>>();
+        input[cells[start % cells.len()]] = b'S';
+        let input = String::from_utf8(input).unwrap();
+
+        prop_assert_eq!(cal_plot_count(&input, steps).unwrap(), naive_plot_count(&input, steps, false));
+        prop_assert_eq!(cal_plot_count_extending(&input, steps).unwrap(), naive_plot_count(&input, steps, true));
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Rock,
+    Garden,
+    Start,
+}
+
+impl Cell for Field {
+    const EXPECTED: &'static str = "'#', '.' or 'S'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Field::Rock),
+            '.' => Some(Field::Garden),
+            'S' => Some(Field::Start),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Field::Rock => '#',
+            Field::Garden => '.',
+            Field::Start => 'S',
+        }
+    }
+}
+
+struct Map {
+    fields: Grid<Field>,
+    // signed, the map repeats in every direction when extending
+    seated: HashSet<IPos>,
+}
+
+impl Map {
+    fn step(&mut self, steps: usize, extending: bool) -> Result<()> {
+        for _ in 0..steps {
+            crate::cancel::check()?;
+            self.seated = self
+                .seated
+                .iter()
+                .flat_map(|pos| Direction::ALL.map(|dir| pos.step(dir)))
+                .filter(|&pos| {
+                    let field = if extending {
+                        Some(self.fields.get_wrapping(pos))
+                    } else {
+                        Pos::try_from(pos).ok().and_then(|pos| self.fields.get(pos))
+                    };
+                    matches!(field, Some(Field::Garden | Field::Start))
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
+    fn count_seated(&self) -> usize {
+        self.seated.len()
+    }
+
+    fn new(input: &str) -> Result<Self> {
+        let fields = Grid::parse(&Source::new(21, input), input)?;
+        let seated = fields
+            .iter()
+            .filter(|(_, field)| **field == Field::Start)
+            .map(|(pos, _)| IPos::from(pos))
+            .collect();
+
+        Ok(Self { fields, seated })
+    }
+}
+
+pub fn cal_plot_count(input: &str, steps: usize) -> Result<usize> {
+    let mut map = Map::new(input)?;
+    map.step(steps, false)?;
+    Ok(map.count_seated())
+}
+
+// TODO impl Day 21.2 faster
+#[allow(dead_code)]
+pub fn cal_plot_count_extending(input: &str, steps: usize) -> Result<usize> {
+    let mut map = Map::new(input)?;
+    map.step(steps, true)?;
+    let seated = map.count_seated();
+    Ok(seated)
+}
+
+pub struct Day21 {
+    pub steps: usize,
+    pub extended_steps: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Self {
+            steps: 64,
+            extended_steps: 26501365,
+        }
+    }
+}
+
+impl Solution for Day21 {
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_plot_count(input, self.steps)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_plot_count_extending(input, self.extended_steps)?.into())
+    }
+
+    fn status(&self, part: Part) -> Status {
+        match part {
+            Part::One => Status::Done,
+            Part::Two => Status::Slow,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::{collections::{HashSet, HashMap}, fmt::Display};
 
-use crate::prelude::*;
+use crate::{prelude::*, visual::{self, Frame}};
 
 #[allow(dead_code)]
 const INPUT: &str = "1,0,1~1,2,1
@@ -16,11 +16,29 @@ fn test_loose_bricks() {
     assert_eq!(cal_loose_bricks(INPUT).unwrap(), 5);
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_loose_bricks);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day22);
+}
+
 #[test]
 fn test_fallings_bricks() {
     assert_eq!(cal_falling_bricks(INPUT).unwrap(), 7);
 }
 
+#[test]
+fn test_invalid_bricks() {
+    let err = cal_loose_bricks("1,0,1~1,2,1\n0,0,2~2,0").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 7, .. })));
+    assert!(matches!(cal_loose_bricks("1,0,1 1,2,1"), Err(AocError::Parse(ParseError { line: 1, .. }))));
+    assert!(matches!(cal_loose_bricks("1,2,1~1,0,1"), Err(AocError::Parse(ParseError { line: 1, column: 7, .. }))));
+}
+
 type BrickId = usize;
 type Position = (usize, usize, usize);
 
@@ -57,36 +75,41 @@ impl Display for Pile {
 }
 
 impl Pile {
-    fn new(input: &str) -> Self {
-        let mut bricks = input
+    fn parse_pos(source: &Source, text: &str) -> Result<Position> {
+        let coords = text.split(',').map(|s| source.num(s)).collect::<Result<Vec<_>>>()?;
+        match coords[..] {
+            [x, y, z] => Ok((x, y, z)),
+            _ => Err(source.error(text, "x,y,z")),
+        }
+    }
+
+    fn new(input: &str) -> Result<Self> {
+        let source = Source::new(22, input);
+        let mut bricks = source
             .lines()
             .map(|line| {
-                let (start, end) = line.split_once('~').unwrap();
-                let mut startpos = start.split(',');
-                let x = startpos.next().unwrap().parse().unwrap();
-                let y = startpos.next().unwrap().parse().unwrap();
-                let z = startpos.next().unwrap().parse().unwrap();
-                let start = (x, y, z);
-                let mut endpos = end.split(',');
-                let x = endpos.next().unwrap().parse().unwrap();
-                let y = endpos.next().unwrap().parse().unwrap();
-                let z = endpos.next().unwrap().parse().unwrap();
-                let end = (x, y, z);
-                Brick { start, end }
+                let (from, to) = source.split_once(line, "~")?;
+                let start = Pile::parse_pos(&source, from)?;
+                let end = Pile::parse_pos(&source, to)?;
+                // the fall goes through start..=end
+                if start.0 > end.0 || start.1 > end.1 || start.2 > end.2 {
+                    return Err(source.error(to, "an end not below the start"));
+                }
+                Ok(Brick { start, end })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
         bricks.sort_by_key(|brick| brick.start.2);
 
         let max_x = bricks
             .iter()
             .map(|brick| brick.start.0.max(brick.end.0))
             .max()
-            .unwrap();
+            .unwrap_or_default();
         let max_y = bricks
             .iter()
             .map(|brick| brick.start.1.max(brick.end.1))
             .max()
-            .unwrap();
+            .unwrap_or_default();
         let max_height = bricks
             .iter()
             .map(|brick| (brick.start.2..brick.end.2).len() + 1)
@@ -98,11 +121,11 @@ impl Pile {
             .collect::<Vec<_>>();
 
         let pile = vec![vec![vec![None; max_height]; max_y + 1]; max_x + 1];
-        Self {
+        Ok(Self {
             bricks,
             pile,
             touching,
-        }
+        })
     }
 
     fn fall(&mut self) {
@@ -159,10 +182,28 @@ impl Pile {
                 highest_tile.max(zlen)
             };
 
-            // println!("{}", self);
+            let bricks = self.bricks.len();
+            visual::frame(|| Frame::new(format!("brick {} of {}", id + 1, bricks), self.side_view(highest_tile)));
         }
     }
 
+    /// Looking at the x-z side up to `height`, the front most brick wins. Bricks are letters, cycling after Z.
+    fn side_view(&self, height: usize) -> String {
+        (0..height)
+            .rev()
+            .map(|z| {
+                self.pile
+                    .iter()
+                    .map(|xpile| match xpile.iter().find_map(|ypile| ypile[z]) {
+                        Some(id) => (b'A' + (id % 26) as u8) as char,
+                        None => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     fn get_loose(&self) -> Vec<BrickId> {
         let mut loose = Vec::new();
         for (id, (_under, over)) in self.touching.iter().enumerate() {
@@ -218,15 +259,31 @@ impl Pile {
 }
 
 pub fn cal_loose_bricks(input: &str) -> Result<usize> {
-    let mut pile = Pile::new(input);
+    let mut pile = Pile::new(input)?;
     pile.fall();
     let loose = pile.get_loose();
     Ok(loose.len())
 }
 
 pub fn cal_falling_bricks(input: &str) -> Result<usize> {
-    let mut pile = Pile::new(input);
+    let mut pile = Pile::new(input)?;
     pile.fall();
     let falling = pile.get_falling();
     Ok(falling.iter().sum())
 }
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_loose_bricks(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_falling_bricks(input)?.into())
+    }
+}
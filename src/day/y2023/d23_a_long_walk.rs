@@ -1,9 +1,8 @@
 use std::fmt::Debug;
 
-use array2d::Array2D;
-use enum_iterator::Sequence;
-
-use crate::prelude::*;
+use crate::{geometry::{Direction, Pos}, grid::{Cell, Grid}, prelude::*, visual::{self, Frame}};
+#[cfg(test)]
+use proptest::prelude::*;
 
 #[allow(dead_code)]
 const INPUT: &str = "#.#####################
@@ -35,6 +34,16 @@ fn test_longest_hike() {
     assert_eq!(cal_longest_hike(INPUT).unwrap(), 94);
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_longest_hike);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day23);
+}
+
 #[test]
 fn test_longest_hike_noslope() {
     assert_eq!(cal_longest_hike_noslope(INPUT).unwrap(), 154);
@@ -68,16 +77,30 @@ fn test_4way() {
 
     assert_eq!(cal_longest_hike_noslope(input).unwrap(), 118);
 }
-type Walked = bool;
-
-#[derive(Sequence, Copy, Clone, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+// the hike branches on the neighbours in this order. The search only works on trails shaped like the puzzle,
+// so instead of the whole day the steps are checked against the (row, col) deltas it used before the shared geometry
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_steps_like_deltas(rows in 1..8_usize, cols in 1..8_usize, row in 0..8_usize, col in 0..8_usize) {
+        prop_assume!(row < rows && col < cols);
+        let trail = Grid::new(rows, cols, Tile::Forest);
+        // Up, Down, Left, Right like the old Direction
+        let old = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter_map(|(d_row, d_col)| {
+                let (row, col) = (row as isize + d_row, col as isize + d_col);
+                let inside = (0..rows as isize).contains(&row) && (0..cols as isize).contains(&col);
+                inside.then(|| Pos::new(row as usize, col as usize))
+            })
+            .collect::<Vec<_>>();
+        let new = Direction::ALL.into_iter().filter_map(|dir| trail.step(Pos::new(row, col), dir)).collect::<Vec<_>>();
+        prop_assert_eq!(new, old);
+    }
 }
 
+type Walked = bool;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Path(Walked),
@@ -85,62 +108,50 @@ enum Tile {
     Slope(Walked, Direction),
 }
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-// row, col starting on top left
-struct Position(usize, usize);
-
-impl Position {
-    fn walk(&self, dir: Direction) -> Option<Self> {
-        match dir {
-            Direction::Up => {
-                if self.0 == 0 {
-                    None
-                } else {
-                    Some(Position(self.0 - 1, self.1))
-                }
-            }
-            Direction::Down => Some(Position(self.0 + 1, self.1)),
-            Direction::Left => {
-                if self.1 == 0 {
-                    None
-                } else {
-                    Some(Position(self.0, self.1 - 1))
-                }
-            }
-            Direction::Right => Some(Position(self.0, self.1 + 1)),
+impl Cell for Tile {
+    const EXPECTED: &'static str = "'#', '.' or a slope out of ><^v";
+
+    fn from_char(c: char) -> Option<Self> {
+        let tile = match c {
+            '#' => Tile::Forest,
+            '.' => Tile::Path(false),
+            '>' => Tile::Slope(false, Direction::Right),
+            '<' => Tile::Slope(false, Direction::Left),
+            '^' => Tile::Slope(false, Direction::Up),
+            'v' => Tile::Slope(false, Direction::Down),
+            _ => return None,
+        };
+        Some(tile)
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Path(false) => '.',
+            Tile::Path(true) => 'O',
+            Tile::Forest => '#',
+            Tile::Slope(true, _) => 'O',
+            Tile::Slope(false, dir) => match dir {
+                Direction::Up => '^',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+                Direction::Right => '>',
+            },
         }
     }
 }
 
 #[derive(Clone)]
 struct Trail {
-    trail: Array2D<Tile>,
-    walk_pos: Position,
-    dead_pos: Position,
-    end: Position,
+    trail: Grid<Tile>,
+    walk_pos: Pos,
+    dead_pos: Pos,
+    end: Pos,
     path_len: usize,
 }
 
 impl Debug for Trail {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in self.trail.rows_iter() {
-            for tile in row {
-                match tile {
-                    Tile::Path(false) => write!(f, ".")?,
-                    Tile::Path(true) => write!(f, "O")?,
-                    Tile::Forest => write!(f, "#")?,
-                    Tile::Slope(true, _) => write!(f, "O")?,
-                    Tile::Slope(false, dir) => match dir {
-                        Direction::Up => write!(f, "^")?,
-                        Direction::Down => write!(f, "v")?,
-                        Direction::Left => write!(f, "<")?,
-                        Direction::Right => write!(f, ">")?,
-                    },
-                }
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        writeln!(f, "{}", self.trail)
     }
 }
 
@@ -150,66 +161,53 @@ enum WalkResult {
 }
 
 impl Trail {
-    fn new(input: &str) -> Self {
-        let rows = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| match c {
-                        '#' => Tile::Forest,
-                        '.' => Tile::Path(false),
-                        '>' => Tile::Slope(false, Direction::Right),
-                        '<' => Tile::Slope(false, Direction::Left),
-                        '^' => Tile::Slope(false, Direction::Up),
-                        'v' => Tile::Slope(false, Direction::Down),
-                        _ => panic!("invalid input"),
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>();
+    fn new(input: &str) -> Result<Self> {
+        let source = Source::new(23, input);
+        let trail: Grid<Tile> = Grid::parse(&source, input)?;
+        let lines = input.lines().collect::<Vec<_>>();
 
-        let start = rows[0]
+        let last = trail.rows().checked_sub(1).ok_or_else(|| source.error(input, "a trail"))?;
+        let start = trail
+            .row(0)
             .iter()
             .position(|&tile| tile == Tile::Path(false))
-            .unwrap();
-        let end = rows[rows.len() - 1]
+            .ok_or_else(|| source.error(lines[0], "a path in the first row"))?;
+        let end = trail
+            .row(last)
             .iter()
             .position(|&tile| tile == Tile::Path(false))
-            .unwrap();
+            .ok_or_else(|| source.error(lines[last], "a path in the last row"))?;
 
-        Self {
-            trail: Array2D::from_rows(&rows).unwrap(),
-            walk_pos: Position(0, start),
-            end: Position(rows.len() - 1, end),
+        Ok(Self {
+            trail,
+            walk_pos: Pos::new(0, start),
+            end: Pos::new(last, end),
             path_len: 0,
-            dead_pos: Position(0, start),
-        }
+            dead_pos: Pos::new(0, start),
+        })
     }
 
     fn get_max_possible_hike_len(&self) -> usize {
         let free_tiles = self
             .trail
-            .elements_row_major_iter()
-            .filter(|tile| matches!(tile, Tile::Path(false) | Tile::Slope(false, _)))
+            .iter()
+            .filter(|(_, tile)| matches!(tile, Tile::Path(false) | Tile::Slope(false, _)))
             .count();
         free_tiles + self.path_len
     }
 
-    fn get_hike_len(mut self, ignore_slope: bool) -> usize {
-        *self
-            .trail
-            .get_mut(self.walk_pos.0, self.walk_pos.1)
-            .unwrap() = Tile::Path(true);
-        *self.trail.get_mut(self.end.0, self.end.1).unwrap() = Tile::Path(true);
+    fn get_hike_len(mut self, ignore_slope: bool) -> Result<usize> {
+        self.trail[self.walk_pos] = Tile::Path(true);
+        self.trail[self.end] = Tile::Path(true);
         // self.path_len += 1;
         self.hike(ignore_slope)
     }
 
-    fn hike(self, ignore_slope: bool) -> usize {
+    fn hike(self, ignore_slope: bool) -> Result<usize> {
         let mut max_path = 0;
         let mut queue = vec![self];
         while let Some(trail) = queue.pop() {
-            
+            crate::cancel::check()?;
             if trail.get_max_possible_hike_len() <= max_path {
                 continue;
             }
@@ -221,27 +219,28 @@ impl Trail {
                 WalkResult::Walked(mut paths) => queue.append(&mut paths),
             }
         }
-        max_path
+        Ok(max_path)
     }
 
     fn walk(self, ignore_slope: bool) -> WalkResult {
         let mut queue = vec![self];
         while let Some(trail) = queue.pop() {
-            // println!("{:?}", trail);
+            visual::frame(|| Frame::grid(format!("walked {} at {:?}", trail.path_len, trail.walk_pos), &trail.trail));
             if trail.walk_pos == trail.end {
                 return WalkResult::End(trail.path_len);
             }
 
-            let mut new_trails = enum_iterator::all::<Direction>()
-                .filter_map(|dir| trail.walk_pos.walk(dir).map(|pos| (dir, pos)))
+            let mut new_trails = Direction::ALL
+                .into_iter()
+                .filter_map(|dir| trail.trail.step(trail.walk_pos, dir).map(|pos| (dir, pos)))
                 .filter_map(|(walk_dir, pos)| {
-                    let tile = trail.trail.get(pos.0, pos.1).unwrap();
+                    let tile = &trail.trail[pos];
                     match tile {
                         Tile::Forest => None,
                         Tile::Path(true) => None,
                         Tile::Path(false) => {
                             let mut new_trail = trail.clone();
-                            *new_trail.trail.get_mut(pos.0, pos.1).unwrap() = Tile::Path(true);
+                            new_trail.trail[pos] = Tile::Path(true);
                             new_trail.dead_pos = new_trail.walk_pos;
                             new_trail.walk_pos = pos;
                             new_trail.path_len += 1;
@@ -251,8 +250,7 @@ impl Trail {
                         Tile::Slope(false, slope_dir) => {
                             if walk_dir == *slope_dir || ignore_slope {
                                 let mut new_trail = trail.clone();
-                                *new_trail.trail.get_mut(pos.0, pos.1).unwrap() =
-                                    Tile::Slope(true, *slope_dir);
+                                new_trail.trail[pos] = Tile::Slope(true, *slope_dir);
                                 new_trail.dead_pos = new_trail.walk_pos;
                                 new_trail.walk_pos = pos;
                                 new_trail.path_len += 1;
@@ -297,17 +295,18 @@ impl Trail {
         let mut walked = false;
         let mut queue = vec![self.dead_pos];
         while let Some(pos) = queue.pop() {
-            // println!("{:?}", self);
+            visual::frame(|| Frame::grid(format!("closing dead end at {:?}", pos), &self.trail));
             if pos == self.end {
                 // dead end cannot walk to end bc other paths might find it
-                *self.trail.get_mut(pos.0, pos.1).unwrap() = Tile::Path(false);
+                self.trail[pos] = Tile::Path(false);
                 return Some(self);
             }
             self.dead_pos = pos;
-            let possible_ways = enum_iterator::all::<Direction>()
-                .filter_map(|dir| self.dead_pos.walk(dir))
+            let possible_ways = self
+                .trail
+                .neighbours4(self.dead_pos)
                 .filter(|pos| {
-                    let tile = self.trail.get(pos.0, pos.1).unwrap();
+                    let tile = &self.trail[*pos];
                     match tile {
                         Tile::Forest => false,
                         Tile::Path(true) => false,
@@ -320,7 +319,7 @@ impl Trail {
 
             //check with cur pos O or #
             let cur_is_path = matches!(
-                self.trail.get(self.dead_pos.0, self.dead_pos.1).unwrap(),
+                self.trail[self.dead_pos],
                 Tile::Path(true) | Tile::Slope(true, _)
             );
 
@@ -328,7 +327,7 @@ impl Trail {
                 (1, _) | (2, true) => {
                     for pos in possible_ways {
                         queue.push(pos);
-                        *self.trail.get_mut(pos.0, pos.1).unwrap() = Tile::Forest;
+                        self.trail[pos] = Tile::Forest;
                     }
                     walked = true;
                 }
@@ -336,11 +335,7 @@ impl Trail {
                 _ => {
                     if walked {
                         // step back
-                        let prev_tile = self
-                            .trail
-                            .get_mut(self.dead_pos.0, self.dead_pos.1)
-                            .unwrap();
-                        *prev_tile = Tile::Path(false);
+                        self.trail[self.dead_pos] = Tile::Path(false);
                     }
                 }
             }
@@ -357,11 +352,12 @@ impl Trail {
         let mut queue = vec![self.end];
         while let Some(pos) = queue.pop() {
             self.end = pos;
-            // println!("{:?}", self);
-            let possible_ways = enum_iterator::all::<Direction>()
-                .filter_map(|dir| self.end.walk(dir))
+            visual::frame(|| Frame::grid(format!("walking back from the end at {:?}", pos), &self.trail));
+            let possible_ways = self
+                .trail
+                .neighbours4(self.end)
                 .filter(|pos| {
-                    let tile = self.trail.get(pos.0, pos.1);
+                    let tile = self.trail.get(*pos);
                     if let Some(tile) = tile {
                         match tile {
                             Tile::Forest => false,
@@ -383,11 +379,11 @@ impl Trail {
                 1 => {
                     self.path_len += 1;
                     let pos = possible_ways.into_iter().next().unwrap();
-                    *self.trail.get_mut(pos.0, pos.1).unwrap() = Tile::Path(true);
+                    self.trail[pos] = Tile::Path(true);
                     queue.push(pos);
                 }
                 _ => {
-                    *self.trail.get_mut(self.end.0, self.end.1).unwrap() = Tile::Path(false);
+                    self.trail[self.end] = Tile::Path(false);
                     return Some(self);
                 }
             }
@@ -397,14 +393,37 @@ impl Trail {
 }
 
 pub fn cal_longest_hike(input: &str) -> Result<usize> {
-    let trail = Trail::new(input);
-    let longest = trail.get_hike_len(false);
+    let trail = Trail::new(input)?;
+    let longest = trail.get_hike_len(false)?;
     Ok(longest)
 }
 
 #[allow(dead_code)]
 pub fn cal_longest_hike_noslope(input: &str) -> Result<usize> {
-    let trail = Trail::new(input);
-    let longest = trail.get_hike_len(true);
+    let trail = Trail::new(input)?;
+    let longest = trail.get_hike_len(true)?;
     Ok(longest)
 }
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_longest_hike(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_longest_hike_noslope(input)?.into())
+    }
+
+    fn status(&self, part: Part) -> Status {
+        match part {
+            Part::One => Status::Done,
+            Part::Two => Status::Slow,
+        }
+    }
+}
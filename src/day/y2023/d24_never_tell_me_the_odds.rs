@@ -17,11 +17,28 @@ fn test_future_intersections() {
     assert_eq!(cal_future_intersections(INPUT, range).unwrap(), 2);
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, |input| cal_future_intersections(input, 7..=27));
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day24 { area: 7..=27 });
+}
+
 #[test]
 fn test_rock_start_sum() {
     assert_eq!(cal_rock_start_sum(INPUT).unwrap(), 47);
 }
 
+#[test]
+fn test_invalid_hail() {
+    let err = cal_future_intersections("19, 13, 30 @ -2,  1, -2\n18, 19 @ -1, -1, -2", 7..=27).unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 1, .. })));
+    assert!(matches!(cal_future_intersections("19, 13, 30 -2, 1, -2", 7..=27), Err(AocError::Parse(ParseError { line: 1, .. }))));
+}
+
 #[derive(Clone, Copy, Debug)]
 struct Vec3(isize, isize, isize);
 
@@ -75,27 +92,31 @@ struct Storm {
 }
 
 impl Storm {
-    fn new(input: &str) -> Self {
-        let hails = input
+    fn parse_vec(source: &Source, text: &str) -> Result<Vec3> {
+        let coords = text
+            .split(',')
+            .map(|s| source.num(s))
+            .collect::<Result<Vec<isize>>>()?;
+        match coords[..] {
+            [x, y, z] => Ok(Vec3(x, y, z)),
+            _ => Err(source.error(text, "x, y, z")),
+        }
+    }
+
+    fn new(input: &str) -> Result<Self> {
+        let source = Source::new(24, input);
+        let hails = source
             .lines()
             .map(|line| {
-                let (pos, vel) = line.split_once(" @ ").unwrap();
-                let pos = pos
-                    .split(", ")
-                    .map(|s| s.trim().parse::<isize>().unwrap())
-                    .collect::<Vec<_>>();
-                let vel = vel
-                    .split(", ")
-                    .map(|s| s.trim().parse::<isize>().unwrap())
-                    .collect::<Vec<_>>();
-                Hail {
-                    pos: Vec3(pos[0], pos[1], pos[2]),
-                    vel: Vec3(vel[0], vel[1], vel[2]),
-                }
+                let (pos, vel) = source.split_once(line, " @ ")?;
+                Ok(Hail {
+                    pos: Storm::parse_vec(&source, pos)?,
+                    vel: Storm::parse_vec(&source, vel)?,
+                })
             })
-            .collect();
+            .collect::<Result<_>>()?;
 
-        Self { hails }
+        Ok(Self { hails })
     }
 
     fn count_intersections(&self, range: RangeInclusive<f64>) -> usize {
@@ -117,7 +138,7 @@ impl Storm {
 }
 
 pub fn cal_future_intersections(input: &str, range: RangeInclusive<isize>) -> Result<usize> {
-    let storm = Storm::new(input);
+    let storm = Storm::new(input)?;
     let range = *range.start() as f64..=*range.end() as f64;
     let count = storm.count_intersections(range);
     Ok(count)
@@ -127,3 +148,37 @@ pub fn cal_future_intersections(input: &str, range: RangeInclusive<isize>) -> Re
 pub fn cal_rock_start_sum(_input: &str) -> Result<isize> {
     todo!("implement throwing the rock perfectly")
 }
+
+pub struct Day24 {
+    /// test area for x and y
+    pub area: RangeInclusive<isize>,
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Self {
+            area: 200_000_000_000_000..=400_000_000_000_000,
+        }
+    }
+}
+
+impl Solution for Day24 {
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_future_intersections(input, self.area.clone())?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_rock_start_sum(input)?.into())
+    }
+
+    fn status(&self, part: Part) -> Status {
+        match part {
+            Part::One => Status::Done,
+            Part::Two => Status::Todo,
+        }
+    }
+}
@@ -92,4 +92,20 @@ pub fn cal_group_size(input: &str) -> Result<usize> {
     let (group1, group2) = system.seperate_by3();
     let mult = group1.len() * group2.len();
     Ok(mult)
-}
\ No newline at end of file
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_group_size(input)?.into())
+    }
+
+    fn status(&self, _part: Part) -> Status {
+        Status::Todo
+    }
+}
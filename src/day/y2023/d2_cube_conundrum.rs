@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use crate::prelude::*;
+
+#[test]
+fn test_cube() {
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    assert_eq!(8, cal_cubes(input).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    crate::input::check_variants(input, cal_cubes);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day2);
+}
+
+#[test]
+fn test_cube2() {
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    assert_eq!(2286, cal_cubes2(input).unwrap());
+}
+
+#[test]
+fn test_game_queries() {
+    let input = "Game 7: 3 blue, 4 red; 1 red, 2 green, 6 blue
+Game 12: 2 purple, 1 red; 5 purple";
+    let games = parse_games(input).unwrap();
+    assert_eq!(games.iter().map(|game| game.id).collect::<Vec<_>>(), vec![7, 12]);
+    assert_eq!(games[0].max("blue"), 6);
+    assert_eq!(games[0].max("purple"), 0);
+    assert_eq!(games[1].min_bag(), Cubes::from([("purple", 5), ("red", 1)]));
+    assert_eq!(games[1].min_bag().power(&["purple", "red"]), 5);
+
+    let bag = Cubes::from([("red", 4), ("green", 2), ("blue", 6)]);
+    let possible = possible_games(&games, &bag).map(|game| game.id).collect::<Vec<_>>();
+    assert_eq!(possible, vec![7]);
+    let bag = Cubes::from([("purple", 5), ("red", 1)]);
+    assert_eq!(possible_games(&games, &bag).map(|game| game.id).collect::<Vec<_>>(), vec![12]);
+}
+
+#[test]
+fn test_repeated_color() {
+    let games = parse_games("Game 1: 1 red, 2 blue, 2 red; 1 red").unwrap();
+    assert_eq!(games[0].rounds[0], Cubes::from([("red", 3), ("blue", 2)]));
+    assert_eq!(games[0].max("red"), 3);
+}
+
+#[test]
+fn test_parse_error() {
+    let AocError::Parse(err) = parse_games("Game 1: 3 blue\nGame 2: three red").unwrap_err() else {
+        panic!("not a parse error");
+    };
+    assert_eq!((err.line, err.column), (2, 9));
+}
+
+/// The bag of part 1
+const BAG: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+/// Colors of the power in part 2
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+pub fn cal_cubes(input: &str) -> Result<usize> {
+    let games = parse_games(input)?;
+    let sum = possible_games(&games, &Cubes::from(BAG)).map(|game| game.id).sum();
+    Ok(sum)
+}
+
+pub fn cal_cubes2(input: &str) -> Result<u32> {
+    let games = parse_games(input)?;
+    let sum = games.iter().map(|game| game.min_bag().power(&COLORS)).sum();
+    Ok(sum)
+}
+
+/// Number of cubes by color, any color name goes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes(BTreeMap<String, u32>);
+
+impl Cubes {
+    /// 0 for colors that aren't there
+    pub fn get(&self, color: &str) -> u32 {
+        self.0.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.0.iter().map(|(color, cnt)| (color.as_str(), *cnt))
+    }
+
+    /// Product of the cubes of these colors
+    pub fn power(&self, colors: &[&str]) -> u32 {
+        colors.iter().map(|color| self.get(color)).product()
+    }
+
+    /// The bag has at least as many cubes of every color
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.colors().all(|(color, cnt)| cnt <= bag.get(color))
+    }
+}
+
+impl<S: Into<String>, const N: usize> From<[(S, u32); N]> for Cubes {
+    fn from(cubes: [(S, u32); N]) -> Self {
+        Self(cubes.into_iter().map(|(color, cnt)| (color.into(), cnt)).collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: usize,
+    /// the cubes shown in each round
+    pub rounds: Vec<Cubes>,
+}
+
+impl Game {
+    fn parse(source: &Source, line: &str) -> Result<Self> {
+        let (game, rounds) = source.split_once(line, ": ")?;
+        let id = game.strip_prefix("Game ").ok_or_else(|| source.error(game, "\"Game <id>\""))?;
+        let rounds = rounds
+            .split(';')
+            .map(|round| {
+                // a color named twice in a round is shown all at once, "1 red, 2 red" are 3 red
+                let mut cubes = Cubes::default();
+                for shown in round.split(',') {
+                    let (cnt, color) = source.split_once(shown.trim(), " ")?;
+                    *cubes.0.entry(color.to_owned()).or_default() += source.num::<u32>(cnt)?;
+                }
+                Ok(cubes)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { id: source.num(id)?, rounds })
+    }
+
+    /// Most cubes of a color shown at once
+    pub fn max(&self, color: &str) -> u32 {
+        self.rounds.iter().map(|round| round.get(color)).max().unwrap_or(0)
+    }
+
+    /// The fewest cubes of every color the game is possible with
+    pub fn min_bag(&self) -> Cubes {
+        let mut bag = Cubes::default();
+        for (color, cnt) in self.rounds.iter().flat_map(Cubes::colors) {
+            let max = bag.0.entry(color.to_owned()).or_default();
+            *max = (*max).max(cnt);
+        }
+        bag
+    }
+
+    pub fn is_possible(&self, bag: &Cubes) -> bool {
+        self.rounds.iter().all(|round| round.fits_in(bag))
+    }
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>> {
+    let source = Source::new(2, input);
+    source.lines().map(|line| Game::parse(&source, line)).collect()
+}
+
+/// The games that could have been played with `bag`
+pub fn possible_games<'a>(games: &'a [Game], bag: &'a Cubes) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(|game| game.is_possible(bag))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_cubes(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_cubes2(input)?.into())
+    }
+}
@@ -0,0 +1,239 @@
+use std::ops::Range;
+
+use crate::{geometry::Pos, grid::Grid, prelude::*};
+
+#[test]
+fn test_gear_ratios() {
+    let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    assert_eq!(4361, cal_gear_ratio(input).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    crate::input::check_variants(input, cal_gear_ratio);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day3);
+}
+
+#[test]
+fn test_gear_ratios2() {
+    let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    assert_eq!(467835, cal_gear_ratio2(input).unwrap());
+}
+
+#[test]
+fn test_numbers_at_line_end() {
+    // the last digit of a line used to be cut off
+    assert_eq!(12, cal_gear_ratio("...*\n..12").unwrap());
+    assert_eq!(36, cal_gear_ratio2("12*\n..3").unwrap());
+}
+
+#[test]
+fn test_queries() {
+    let input = "2*3.5
+.....
+7#..1
+..11.";
+    let schematic = Schematic::parse(input).unwrap();
+    let gears = schematic.query('*', 2).map(|around| (around.symbol.pos, around.product().unwrap())).collect::<Vec<_>>();
+    assert_eq!(gears, vec![(Pos::new(0, 1), 6)]);
+    let hashes = schematic.query('#', 2).map(|around| around.sum().unwrap()).collect::<Vec<_>>();
+    assert_eq!(hashes, vec![18]);
+    assert_eq!(schematic.query('#', 1).count(), 0);
+    // the product of a gear doesn't fit into 32 bit
+    let big = Schematic::parse("100000*100000").unwrap();
+    assert_eq!(big.query('*', 2).map(|gear| gear.product()).collect::<Vec<_>>(), vec![Some(10_000_000_000)]);
+    // 5 and 1 touch nothing
+    let parts = schematic.part_numbers().map(|number| number.value).collect::<Vec<_>>();
+    assert_eq!(parts, vec![2, 3, 7, 11]);
+}
+
+#[test]
+fn test_overflow() {
+    assert_eq!(cal_gear_ratio2("4294967295*4294967297").unwrap(), u64::MAX);
+    assert!(cal_gear_ratio2("4294967296*4294967296").is_err());
+    // too many digits for a u64
+    assert!(matches!(cal_gear_ratio(".*123456789012345678901234567890"), Err(AocError::Parse(ParseError { line: 1, column: 3, .. }))));
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u64,
+    pub row: usize,
+    pub cols: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub pos: Pos,
+}
+
+/// A symbol and the numbers around it, each number only once
+#[derive(Debug, Clone)]
+pub struct Neighbours<'a> {
+    pub symbol: &'a Symbol,
+    pub numbers: Vec<&'a Number>,
+}
+
+impl Neighbours<'_> {
+    /// None if it overflows
+    pub fn product(&self) -> Option<u64> {
+        self.numbers.iter().try_fold(1_u64, |product, number| product.checked_mul(number.value))
+    }
+
+    /// None if it overflows
+    pub fn sum(&self) -> Option<u64> {
+        self.numbers.iter().try_fold(0_u64, |sum, number| sum.checked_add(number.value))
+    }
+}
+
+/// Numbers and symbols of the engine, with the numbers around every symbol looked up once while parsing
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// indices into `numbers`, for every symbol
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+
+        let source = Source::new(3, input);
+        let schematic: Grid<char> = Grid::parse(&source, input)?;
+
+        for (row, (text, line)) in input.lines().zip(schematic.rows_iter()).enumerate() {
+            let mut start = None;
+            // one past the end flushes numbers at the end of the line
+            for col in 0..=line.len() {
+                let c = line.get(col).copied().unwrap_or('.');
+                match (c.to_digit(10), start) {
+                    (Some(_), None) => start = Some(col),
+                    (Some(_), Some(_)) => {}
+                    (None, Some(first)) => {
+                        // digits are ascii, so the columns are byte offsets once the line starts with them
+                        let from = text.char_indices().nth(first).map_or(0, |(i, _)| i);
+                        let value = source.num(&text[from..from + col - first])?;
+                        numbers.push(Number { value, row, cols: first..col });
+                        start = None;
+                    }
+                    (None, None) => {}
+                }
+                if c != '.' && !c.is_ascii_digit() {
+                    symbols.push(Symbol { symbol: c, pos: Pos::new(row, col) });
+                }
+            }
+        }
+
+        // which number covers a cell, so every symbol only looks at its 8 neighbours
+        let mut covered = Grid::new(schematic.rows(), schematic.cols(), None);
+        for (i, number) in numbers.iter().enumerate() {
+            for col in number.cols.clone() {
+                covered[Pos::new(number.row, col)] = Some(i);
+            }
+        }
+        let neighbours = symbols
+            .iter()
+            .map(|symbol| {
+                let mut around = covered.neighbours8(symbol.pos).filter_map(|pos| covered[pos]).collect::<Vec<_>>();
+                around.sort_unstable();
+                around.dedup();
+                around
+            })
+            .collect();
+
+        Ok(Self { numbers, symbols, neighbours })
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// Every symbol with the numbers around it
+    pub fn neighbours(&self) -> impl Iterator<Item = Neighbours<'_>> {
+        self.symbols.iter().zip(&self.neighbours).map(|(symbol, around)| Neighbours {
+            symbol,
+            numbers: around.iter().map(|i| &self.numbers[*i]).collect(),
+        })
+    }
+
+    /// Symbols `symbol` with exactly `count` numbers around, `('*', 2)` are the gears
+    pub fn query(&self, symbol: char, count: usize) -> impl Iterator<Item = Neighbours<'_>> {
+        self.neighbours().filter(move |around| around.symbol.symbol == symbol && around.numbers.len() == count)
+    }
+
+    /// Numbers next to any symbol, in reading order
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        let mut is_part = vec![false; self.numbers.len()];
+        for i in self.neighbours.iter().flatten() {
+            is_part[*i] = true;
+        }
+        self.numbers.iter().zip(is_part).filter_map(|(number, is_part)| is_part.then_some(number))
+    }
+}
+
+pub fn cal_gear_ratio(input: &str) -> Result<u64> {
+    let schematic = Schematic::parse(input)?;
+    let sum = schematic.part_numbers().try_fold(0_u64, |sum, number| sum.checked_add(number.value));
+    sum.ok_or_else(|| "sum of the part numbers overflows".into())
+}
+
+pub fn cal_gear_ratio2(input: &str) -> Result<u64> {
+    let schematic = Schematic::parse(input)?;
+    let ratios = schematic.query('*', 2).try_fold(0_u64, |sum, gear| sum.checked_add(gear.product()?));
+    ratios.ok_or_else(|| "sum of the gear ratios overflows".into())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_gear_ratio(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_gear_ratio2(input)?.into())
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::HashSet;
+
+use crate::prelude::*;
+
+#[test]
+fn test_scratchcards() {
+    let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    assert_eq!(13, cal_card_points(input).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    crate::input::check_variants(input, cal_card_points);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day4);
+}
+
+#[test]
+fn test_scratchcards2() {
+    let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    assert_eq!(30, count_cards(input).unwrap());
+}
+
+#[test]
+fn test_scoring() {
+    let cards = Scratchcards::parse("Card 1: 1 2 3 | 1 2 3\nCard 2: 4 | 5").unwrap();
+    assert_eq!(cards.points(Scoring::Doubling).unwrap(), 4);
+    assert_eq!(cards.points(Scoring::Linear).unwrap(), 3);
+    assert_eq!(cards.points(Scoring::Custom(|matches| matches as u64 * 10 + 1)).unwrap(), 32);
+
+    // 65 matches are worth more than a u64
+    assert_eq!(Scoring::Doubling.points(64).unwrap(), 1 << 63);
+    assert!(Scoring::Doubling.points(65).is_err());
+    let numbers = (1..=65).map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+    let cards = Scratchcards::parse(&format!("Card 1: {} | {}", numbers, numbers)).unwrap();
+    assert!(cards.points(Scoring::Doubling).is_err());
+}
+
+#[test]
+fn test_overflow() {
+    let cards = Scratchcards::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 4").unwrap();
+    assert_eq!(cards.copies(Overflow::Clamp).unwrap(), vec![1, 2]);
+    assert!(cards.copies(Overflow::Error).is_err());
+    // a card doesn't win a copy of itself
+    assert_eq!(cards.copies(Overflow::Wrap).unwrap(), vec![1, 2]);
+
+    // as many matches as cards or more win every other card once
+    let cards = Scratchcards::parse("Card 1: 1 2 3 | 1 2 3\nCard 2: 5 | 6\nCard 3: 7 | 8").unwrap();
+    assert_eq!(cards.copies(Overflow::Wrap).unwrap(), vec![1, 2, 2]);
+    let cards = Scratchcards::parse("Card 1: 5 | 6\nCard 2: 1 2 3 4 5 | 1 2 3 4 5\nCard 3: 7 | 8").unwrap();
+    assert_eq!(cards.copies(Overflow::Wrap).unwrap(), vec![2, 1, 2]);
+    let trace = cards.trace(Overflow::Wrap).unwrap();
+    assert_eq!(trace[1], vec![0, 1, 0]);
+}
+
+#[test]
+fn test_too_many_copies() {
+    // every card wins the next two, the copies grow like the Fibonacci numbers
+    let input = (1..=100).map(|id| format!("Card {}: 1 2 | 1 2", id)).collect::<Vec<_>>().join("\n");
+    let cards = Scratchcards::parse(&input).unwrap();
+    assert!(cards.copies(Overflow::Clamp).is_err());
+    assert!(cards.trace(Overflow::Clamp).is_err());
+}
+
+#[test]
+fn test_duplicate_numbers() {
+    let err = Scratchcards::parse("Card 1: 1 2 | 3\nCard 2: 4 5 4 | 6").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 13, .. })));
+    assert!(Scratchcards::parse("Card 1: 1 2 | 3 3").is_err());
+    // the same number on both sides is a match
+    assert!(Scratchcards::parse("Card 1: 1 2 | 2 3").is_ok());
+}
+
+#[test]
+fn test_trace() {
+    let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    let cards = Scratchcards::parse(input).unwrap();
+    let trace = cards.trace(Overflow::Error).unwrap();
+    assert_eq!(trace[1], vec![1, 1, 0, 0, 0, 0]);
+    assert_eq!(trace[2], vec![2, 1, 1, 0, 0, 0]);
+    let copies = trace.iter().map(|origins| origins.iter().sum()).collect::<Vec<u64>>();
+    assert_eq!(copies, cards.copies(Overflow::Error).unwrap());
+}
+
+pub fn cal_card_points(input: &str) -> Result<u64> {
+    Scratchcards::parse(input)?.points(Scoring::Doubling)
+}
+
+pub fn count_cards(input: &str) -> Result<u64> {
+    // the puzzle promises that no card wins copies past the end
+    let copies = Scratchcards::parse(input)?.copies(Overflow::Error)?;
+    Ok(copies.iter().sum())
+}
+
+/// Points of a card from its number of matches
+#[derive(Debug, Clone, Copy)]
+pub enum Scoring {
+    /// 1 for the first match, doubled for every other one like part 1
+    Doubling,
+    /// 1 per match
+    Linear,
+    Custom(fn(u32) -> u64),
+}
+
+impl Scoring {
+    /// Doubling past 64 matches doesn't fit into a u64 and is an error
+    pub fn points(&self, matches: u32) -> Result<u64> {
+        match self {
+            Scoring::Doubling if matches == 0 => Ok(0),
+            Scoring::Doubling => 1_u64
+                .checked_shl(matches - 1)
+                .ok_or_else(|| format!("{} matches are worth more than {} points", matches, u64::MAX).into()),
+            Scoring::Linear => Ok(matches as u64),
+            Scoring::Custom(points) => Ok(points(matches)),
+        }
+    }
+}
+
+/// What happens to copies won past the last card
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// they are lost
+    Clamp,
+    Error,
+    /// they continue at the first card. As that card was scratched already, the copies don't win anything.
+    /// They wrap only once and stop before the winning card, so no card is won twice or wins itself.
+    Wrap,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: usize,
+    pub winning: HashSet<u32>,
+    pub numbers: Vec<u32>,
+}
+
+impl Card {
+    fn parse(source: &Source, line: &str) -> Result<Self> {
+        let (card, numbers) = source.split_once(line, ":")?;
+        let id = card.strip_prefix("Card").ok_or_else(|| source.error(card, "\"Card <id>\""))?;
+        let (winning, numbers) = source.split_once(numbers, "|")?;
+        let winning = Card::parse_numbers(source, winning)?.into_iter().collect();
+        let numbers = Card::parse_numbers(source, numbers)?;
+        Ok(Self { id: source.num(id)?, winning, numbers })
+    }
+
+    /// A number listed twice would count twice, so that's an error
+    fn parse_numbers(source: &Source, text: &str) -> Result<Vec<u32>> {
+        let mut seen = HashSet::new();
+        text.split_whitespace()
+            .map(|n| {
+                let number = source.num(n)?;
+                if !seen.insert(number) {
+                    return Err(source.error(n, "every number only once"));
+                }
+                Ok(number)
+            })
+            .collect()
+    }
+
+    pub fn matches(&self) -> u32 {
+        self.numbers.iter().filter(|n| self.winning.contains(n)).count() as u32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scratchcards {
+    cards: Vec<Card>,
+}
+
+impl Scratchcards {
+    pub fn parse(input: &str) -> Result<Self> {
+        let source = Source::new(4, input);
+        let cards = source.lines().map(|line| Card::parse(&source, line)).collect::<Result<_>>()?;
+        Ok(Self { cards })
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn points(&self, scoring: Scoring) -> Result<u64> {
+        self.cards.iter().try_fold(0_u64, |sum, card| {
+            sum.checked_add(scoring.points(card.matches())?).ok_or_else(|| "sum of the points overflows".into())
+        })
+    }
+
+    /// How many of every card there are in the end, originals included
+    pub fn copies(&self, overflow: Overflow) -> Result<Vec<u64>> {
+        self.scratch(overflow, |_| 1, |copies, won| add_copies(copies, *won))
+    }
+
+    /// For every card the copies that go back to each original card, `trace[card][original]`.
+    /// Both are indices into [Scratchcards::cards], the sum of a row is the number of copies.
+    pub fn trace(&self, overflow: Overflow) -> Result<Vec<Vec<u64>>> {
+        let len = self.cards.len();
+        self.scratch(
+            overflow,
+            |card| {
+                let mut origins = vec![0; len];
+                origins[card] = 1;
+                origins
+            },
+            |origins, won| origins.iter_mut().zip(won).try_for_each(|(origin, won)| add_copies(origin, *won)),
+        )
+    }
+
+    /// Scratches the cards in order, every copy of a card wins the same following cards.
+    /// `start` are the originals of a card, `add` adds the copies of a winning card to a won one.
+    fn scratch<T: Clone>(&self, overflow: Overflow, start: impl Fn(usize) -> T, add: impl Fn(&mut T, &T) -> Result<()>) -> Result<Vec<T>> {
+        let len = self.cards.len();
+        let mut copies = (0..len).map(start).collect::<Vec<_>>();
+        for (i, card) in self.cards.iter().enumerate() {
+            let won = copies[i].clone();
+            // one lap at most, stopping before the card itself
+            let last = match overflow {
+                Overflow::Wrap => i + (card.matches() as usize).min(len - 1),
+                _ => i + card.matches() as usize,
+            };
+            for next in i + 1..=last {
+                let next = match overflow {
+                    _ if next < len => next,
+                    Overflow::Clamp => break,
+                    Overflow::Error => {
+                        return Err(format!("card {} wins copies past the last card {}", card.id, len).into());
+                    }
+                    Overflow::Wrap => next % len,
+                };
+                add(&mut copies[next], &won)?;
+            }
+        }
+        Ok(copies)
+    }
+}
+
+fn add_copies(copies: &mut u64, won: u64) -> Result<()> {
+    *copies = copies.checked_add(won).ok_or("number of copies overflows")?;
+    Ok(())
+}
+
+#[test]
+fn test_range() {
+    for i in (0..5).rev() {
+        println!("{}", i);
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_card_points(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(count_cards(input)?.into())
+    }
+}
@@ -0,0 +1,343 @@
+use std::{marker::PhantomData, ops::Range};
+
+use crate::prelude::*;
+
+#[allow(dead_code)]
+const TEST_INPUT: &str = 
+"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+#[test]
+fn test_fertilizer() {
+    assert_eq!(35, cal_lowest_location(TEST_INPUT).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(TEST_INPUT, cal_lowest_location);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day5);
+}
+
+#[test]
+fn test_fertilizer2() {
+    assert_eq!(46, cal_lowest_loc_ranges(TEST_INPUT).unwrap());
+}
+
+#[test]
+fn test_map_range() {
+    let regions: IdMap<Seed, Soil> = vec![Region::new(50, 98, 2), Region::new(52, 50, 48)];
+    fn range<T>(start: u64, end: u64) -> Range<Id<T>> {
+        Id::from(start)..Id::from(end)
+    }
+    // split at both region boundaries, the ids before 50 stay the same
+    assert_eq!(Region::map_range(&regions, &range(40, 100)), vec![range(40, 50), range(52, 100), range(50, 52)]);
+    assert_eq!(Region::map_range(&regions, &range(0, 10)), vec![range(0, 10)]);
+    assert_eq!(Region::map_range(&regions, &range(99, 105)), vec![range(51, 52), range(100, 105)]);
+    assert!(Region::map_range(&regions, &range::<Seed>(7, 7)).is_empty());
+
+    // overlapping regions, the first one wins like when mapping single ids
+    let regions: IdMap<Seed, Soil> = vec![Region::new(100, 10, 10), Region::new(200, 5, 10)];
+    assert_eq!(Region::map_range(&regions, &range(0, 25)), vec![range(0, 5), range(200, 205), range(100, 110), range(20, 25)]);
+    for id in 0..25 {
+        let mapped = Region::map(&regions, &Id::from(id));
+        let ranges = Region::map_range(&regions, &range(id, id + 1));
+        assert_eq!(ranges, vec![mapped..Id::from(mapped.id + 1)]);
+    }
+}
+
+#[test]
+fn test_invalid_almanac() {
+    let input = TEST_INPUT.replace("37 52 2", "37 5x 2");
+    let err = cal_lowest_location(&input).unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 9, column: 4, .. })));
+
+    let input = TEST_INPUT.replace("37 52 2", "37 52");
+    assert!(cal_lowest_location(&input).is_err());
+    let input = TEST_INPUT.replace("water-to-light", "water-to-lamp");
+    assert!(cal_lowest_location(&input).is_err());
+    assert!(cal_lowest_location(&TEST_INPUT.replace("seeds:", "seed:")).is_err());
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, PartialOrd, Ord)]
+struct Id<T> {
+    id: u64,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> From<u64> for Id<T> {
+    fn from(id: u64) -> Self {
+        Self {
+            id,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"Id({})", self.id)
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord, Debug)]
+struct Seed;
+#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord)]
+struct Soil;
+#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord)]
+struct Fertilizer;
+#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord)]
+struct Water;
+#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord)]
+struct Light;
+#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord)]
+struct Temperature;
+#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord)]
+struct Humidity;
+#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord)]
+struct Location;
+
+#[derive(Debug, Clone, Copy)]
+struct Region<A, B> {
+    dest: u64,
+    src: u64, 
+    len: u64,
+    _phantom: PhantomData<(A, B)>,
+}
+
+impl<Src, Dest> Region<Id<Src>, Id<Dest>> {
+
+    fn new(dest: u64, src: u64, len: u64) -> Self {
+        Self {
+            dest,
+            src,
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn contains_src(&self, id: &Id<Src>) -> bool {
+        let range = self.src..(self.src+self.len);
+        range.contains(&id.id)
+    }
+
+    fn convert(&self, src: &Id<Src>) -> Id<Dest> {
+        Id::<Dest>::from((src.id as i64 - self.src as i64 + self.dest as i64) as u64)
+    }
+
+    fn map(ranges: &[Self], src: &Id<Src>) -> Id<Dest> {
+        match ranges.iter()
+        .find(|r| r.contains_src(src)) {
+            Some(r) => r.convert(src),
+            None => Id::<Dest>::from(src.id),
+        }
+    }
+    
+    /// The whole range at once, split where it crosses region boundaries, ordered by the source ids.
+    /// Like in [Region::map] the first region containing an id wins if regions overlap,
+    /// and parts outside of every region keep their ids.
+    fn map_range(regions: &[Self], range: &Range<Id<Src>>) -> Vec<Range<Id<Dest>>> {
+        let mut unmapped = std::iter::once(range.start.id..range.end.id).filter(|part| !part.is_empty()).collect::<Vec<_>>();
+        let mut mapped = Vec::new();
+        for region in regions {
+            let mut rest = Vec::new();
+            for part in unmapped {
+                let (from, to) = (part.start.max(region.src), part.end.min(region.src + region.len));
+                if from >= to {
+                    rest.push(part);
+                    continue;
+                }
+                mapped.push((from, region.convert(&Id::from(from))..region.convert(&Id::from(to))));
+                rest.extend([part.start..from, to..part.end].into_iter().filter(|left| !left.is_empty()));
+            }
+            unmapped = rest;
+        }
+        mapped.extend(unmapped.into_iter().map(|part| (part.start, Id::from(part.start)..Id::from(part.end))));
+        mapped.sort_by_key(|(from, _)| *from);
+        mapped.into_iter().map(|(_, range)| range).collect()
+    }
+
+    fn map_ranges(regions: &[Self], ranges: &[Range<Id<Src>>]) -> Vec<Range<Id<Dest>>> {
+        ranges.iter().flat_map(|range| Region::map_range(regions, range)).collect()
+    }
+}
+
+type IdMap<A, B> = Vec<Region<Id<A>, Id<B>>>;
+
+struct Almanac {
+    seeds: Vec<Range<Id<Seed>>>,
+    seed_to_soil: IdMap<Seed, Soil>,
+    soil_to_fertilizer: IdMap<Soil, Fertilizer>,
+    fertilizer_to_water: IdMap<Fertilizer, Water>,
+    water_to_light: IdMap<Water, Light>,
+    light_to_temperature: IdMap<Light, Temperature>,
+    temperature_to_humidity: IdMap<Temperature, Humidity>,
+    humidity_to_location: IdMap<Humidity, Location>,
+}
+
+type SeedGenerator = fn(&Source) -> Result<Vec<Range<Id<Seed>>>>;
+
+impl Almanac {
+
+    fn new(input: &str, seed_gen: SeedGenerator) -> Result<Self> {
+        let source = Source::new(5, input);
+        let seeds = seed_gen(&source)?;
+        let seed_to_soil = Almanac::cal_map(&source, input, "seed-to-soil map:\n")?;
+        let soil_to_fertilizer = Almanac::cal_map(&source, input, "soil-to-fertilizer map:\n")?;
+        let fertilizer_to_water = Almanac::cal_map(&source, input, "fertilizer-to-water map:\n")?;
+        let water_to_light = Almanac::cal_map(&source, input, "water-to-light map:\n")?;
+        let light_to_temperature = Almanac::cal_map(&source, input, "light-to-temperature map:\n")?;
+        let temperature_to_humidity = Almanac::cal_map(&source, input, "temperature-to-humidity map:\n")?;
+        let humidity_to_location = Almanac::cal_map(&source, input, "humidity-to-location map:\n")?;
+
+        Ok(Self {
+            seeds,
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location,
+        })
+    }
+
+    fn cal_map<A, B>(source: &Source, input: &str, name: &str) -> Result<IdMap<A, B>> {
+        let (_, section) = input
+            .split_once(name)
+            .ok_or_else(|| source.error(&input[input.len()..], format!("{:?}", name.trim())))?;
+        section.split("\n\n").next().unwrap_or_default()
+        .lines().map(|line| {
+            let vec = line.split(' ').map(|s| source.num(s)).collect::<Result<Vec<u64>>>()?;
+            match vec[..] {
+                [dest, src, len] => Ok(Region::new(dest, src, len)),
+                _ => Err(source.error(line, "destination, source and length")),
+            }
+        }).collect()
+    }
+
+    fn parse_seeds(source: &Source) -> Result<Vec<Id<Seed>>> {
+        let line = source.lines().next().unwrap_or_default();
+        let seeds = line
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| source.error(line, "\"seeds: \""))?;
+        seeds.split(' ').map(|s| {
+            Ok(Id::<Seed>::from(source.num::<u64>(s)?))
+        }).collect()
+    }
+
+    fn list_seeds(source: &Source) -> Result<Vec<Range<Id<Seed>>>> {
+        let seeds = Almanac::parse_seeds(source)?;
+        Ok(seeds.into_iter().map(|id| {
+            id..Id::<Seed>::from(id.id+1)
+        }).collect())
+    }
+
+    fn list_seed_ranges(source: &Source) -> Result<Vec<Range<Id<Seed>>>> {
+        let vec = Almanac::parse_seeds(source)?;
+        if vec.len() % 2 != 0 {
+            let line = source.lines().next().unwrap_or_default();
+            return Err(source.error(line, "pairs of start and length"));
+        }
+        Ok(vec.chunks(2).map(|chunk| {
+            (chunk[0], chunk[1].id)
+        }).map(|(start, len)| {
+            start..Id::<Seed>::from(start.id+len)
+        }).collect())
+
+    }
+
+}
+
+pub fn cal_lowest_location(input: &str) -> Result<u64> {
+
+    let min = cal_location(input, Almanac::list_seeds)?;
+
+    Ok(min)
+}
+
+pub fn cal_lowest_loc_ranges(input: &str) -> Result<u64> {
+    let almanac = Almanac::new(input, Almanac::list_seed_ranges)?;
+    let soil = Region::map_ranges(&almanac.seed_to_soil, &almanac.seeds);
+    let fertilizer = Region::map_ranges(&almanac.soil_to_fertilizer, &soil);
+    let water = Region::map_ranges(&almanac.fertilizer_to_water, &fertilizer);
+    let light = Region::map_ranges(&almanac.water_to_light, &water);
+    let temperature = Region::map_ranges(&almanac.light_to_temperature, &light);
+    let humidity = Region::map_ranges(&almanac.temperature_to_humidity, &temperature);
+    let location = Region::map_ranges(&almanac.humidity_to_location, &humidity);
+    let min = location.iter().map(|range| range.start.id).min().ok_or("no seeds")?;
+    Ok(min)
+}
+
+fn cal_location(input: &str, generator: SeedGenerator) -> Result<u64> {
+    
+    let almanac = Almanac::new(input, generator)?;
+    let min = almanac.seeds.iter().try_fold(u64::MAX, |min, range| {
+        // println!("range: {:?}", range);
+        (range.start.id..range.end.id).map(|id|{
+            Id::<Seed>::from(id)
+        }).map(|seed| {
+            // println!("seed: {:?}", seed);
+            crate::cancel::check()?;
+            let soil = Region::map(&almanac.seed_to_soil, &seed);
+            let fertilizer = Region::map(&almanac.soil_to_fertilizer, &soil);
+            let water = Region::map(&almanac.fertilizer_to_water, &fertilizer);
+            let light = Region::map(&almanac.water_to_light, &water);
+            let temperature = Region::map(&almanac.light_to_temperature, &light);
+            let humidity = Region::map(&almanac.temperature_to_humidity, &temperature);
+            let location = Region::map(&almanac.humidity_to_location, &humidity);
+            Ok(location.id)
+        }).try_fold(min, |min, location: Result<u64>| location.map(|location| location.min(min)))
+    })?;
+    Ok(min)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_lowest_location(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_lowest_loc_ranges(input)?.into())
+    }
+}
@@ -0,0 +1,151 @@
+use crate::prelude::*;
+
+#[allow(dead_code)]
+const INPUT: &str = 
+"Time:      7  15   30
+Distance:  9  40  200";
+
+#[test]
+fn test_way_to_win() {
+    assert_eq!(288, cal_ways_to_win(INPUT).unwrap());
+}
+
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_ways_to_win);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day6);
+}
+
+#[test]
+fn test_way_to_win2() {
+    assert_eq!(71503, cal_ways_to_win2(INPUT).unwrap());
+}
+
+#[test]
+fn test_invalid_races() {
+    let err = cal_ways_to_win("Time:      7  15   30\nDistance:  9  4x  200").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 15, .. })));
+    assert!(matches!(cal_ways_to_win2("Time: 7 15\nDist: 9 40"), Err(AocError::Parse(ParseError { line: 2, .. }))));
+    assert!(matches!(cal_ways_to_win("Time: 7 15\nDistance: 9"), Err(AocError::Parse(ParseError { line: 2, .. }))));
+    assert!(cal_ways_to_win("Time: 7 15").is_err());
+}
+
+type Duration = u64;
+type Speed = u64;
+type Distance = u64;
+
+struct Race {
+    duration: Duration,
+    distance: Distance,
+}
+
+struct Boat {
+    speed: Speed,
+}
+
+impl Boat {
+    fn start(push_time: Duration) -> Self {
+        Self {
+            speed: push_time,
+        }
+    
+    } 
+
+    fn cal_distance(&self, time: Duration) -> Distance {
+        self.speed * time
+    }
+}
+
+pub fn cal_ways_to_win(input: &str) -> Result<u64> {
+    let races = parse_races(input)?;
+    let mult = mult_ways_to_win(&races);    
+    Ok(mult)
+}
+
+pub fn cal_ways_to_win2(input: &str) -> Result<u64> {
+    let races = parse_race(input)?;
+    let mult = mult_ways_to_win(&[races]);    
+    Ok(mult)
+}
+
+fn mult_ways_to_win(races: &[Race]) -> u64 {
+
+    let mult: usize = races.iter()
+    .map(|race| {
+        (1..race.duration)
+        .map(|push_time| {
+            let boat = Boat::start(push_time);
+            let distance = boat.cal_distance(race.duration - push_time);
+            distance > race.distance
+        })
+        .filter(|&win| win)
+        .count()
+        // println!("ways_to_win: {}", ways_to_win);
+        // ways_to_win
+    }).product();
+
+    mult as u64
+}
+
+/// The numbers after `name` in the next line
+fn parse_line<'a>(source: &Source<'a>, input: &'a str, line: Option<&'a str>, name: &str) -> Result<&'a str> {
+    let line = line.ok_or_else(|| source.error(&input[input.len()..], format!("{:?}", name)))?;
+    line.strip_prefix(name).ok_or_else(|| source.error(line, format!("{:?}", name)))
+}
+
+fn parse_races(input: &str) -> Result<Vec<Race>> {
+    let source = Source::new(6, input);
+    let mut lines = source.lines();
+    let times = parse_line(&source, input, lines.next(), "Time:")?;
+    let distances = parse_line(&source, input, lines.next(), "Distance:")?;
+
+    let durations = times.split_whitespace().map(|s| source.num(s)).collect::<Result<Vec<Duration>>>()?;
+    let records = distances.split_whitespace().map(|s| source.num(s)).collect::<Result<Vec<Distance>>>()?;
+    if durations.len() != records.len() {
+        return Err(source.error(distances, format!("{} distances", durations.len())));
+    }
+
+    Ok(durations.into_iter()
+    .zip(records)
+    .map(|(duration, distance)| {
+        Race { duration, distance}
+    }).collect())
+}
+
+/// The numbers of a line with the spaces between them removed
+fn parse_kerned(source: &Source, text: &str) -> Result<u64> {
+    let digits = text.split_whitespace().collect::<String>();
+    if digits.is_empty() {
+        return Err(source.error(text, "a number"));
+    }
+    digits.parse().map_err(|_| source.error(text.trim(), "a number"))
+}
+
+fn parse_race(input: &str) -> Result<Race> {
+    let source = Source::new(6, input);
+    let mut lines = source.lines();
+    let duration = parse_kerned(&source, parse_line(&source, input, lines.next(), "Time:")?)?;
+    let distance = parse_kerned(&source, parse_line(&source, input, lines.next(), "Distance:")?)?;
+
+    Ok(Race { duration, distance })
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_ways_to_win(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_ways_to_win2(input)?.into())
+    }
+}
@@ -1,4 +1,6 @@
 
+use crate::prelude::*;
+
 #[derive(Debug, PartialOrd, Ord, Clone, Copy)]
 enum Card {
     Ace,
@@ -48,8 +50,8 @@ impl PartialEq for Card {
 impl Eq for Card {}
 
 impl Card {
-    fn from(c: char, use_joker: bool) -> Self {
-        match c {
+    fn parse(c: char, use_joker: bool) -> Option<Self> {
+        let card = match c {
             'A' => Self::Ace,
             'K' => Self::King,
             'Q' => Self::Queen,
@@ -63,8 +65,9 @@ impl Card {
             '4' => Self::Four,
             '3' => Self::Three,
             '2' => Self::Two,
-            _ => panic!("Invalid card: {}", c),
-        }
+            _ => return None,
+        };
+        Some(card)
     }
 
     fn is_joker(&self) -> bool {
@@ -87,9 +90,8 @@ enum HandType {
 
 impl HandType {
 
-    fn parse(cards: &[char; 5], use_joker: bool) -> Self {
+    fn parse(unsorted_hand: UnsortedHand) -> Self {
 
-        let unsorted_hand = cards.map(|c| Card::from(c, use_joker));        
         let mut sorted_hand = unsorted_hand;
         sorted_hand.sort();
 
@@ -146,18 +148,19 @@ pub struct Hand {
 
 impl Hand {
 
-    pub fn parse(input: &str, use_joker: bool) -> Vec<Self> {
+    pub fn parse(input: &str, use_joker: bool) -> Result<Vec<Self>> {
 
-       input.lines()
+        let source = Source::new(7, input);
+        input.lines()
         .map(|line| {
-            let (cards, bet) = line.split_once(' ').unwrap();
-            let bet = bet.parse().unwrap();
-            let chars = cards.chars().collect::<Vec<_>>();
-            let hand = HandType::parse(chars.as_slice().try_into().unwrap(), use_joker);
-            Self {
-                cards: hand,
+            let (cards, bet) = source.split_once(line, " ")?;
+            let bet = source.num(bet)?;
+            let hand = source.chars(cards, "a card out of AKQJT98765432", |c| Card::parse(c, use_joker))?;
+            let hand = hand.try_into().map_err(|_| source.error(cards, "5 cards"))?;
+            Ok(Self {
+                cards: HandType::parse(hand),
                 bet,
-            }
+            })
         })
         .collect()
 
@@ -15,6 +15,16 @@ fn test_winning_points() {
     assert_eq!(6440, cal_winning_points(INPUT, false).unwrap());
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, |input| cal_winning_points(input, false));
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day7);
+}
+
 #[test]
 fn test_order() {
     //winnner is 33332 because 3 > 2
@@ -28,10 +38,18 @@ fn test_winning_points_joker() {
     assert_eq!(5905, cal_winning_points(INPUT, true).unwrap());
 }
 
+#[test]
+fn test_invalid_hand() {
+    let err = cal_winning_points("32T3K 765\nT55X5 684", false).unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 4, .. })));
+    assert!(cal_winning_points("32T3 765", false).is_err());
+    assert!(cal_winning_points("32T3K x", false).is_err());
+}
+
 type TotalWinnings = u64;
 
 pub fn cal_winning_points(input: &str, use_joker: bool) -> Result<TotalWinnings> {
-    let mut games = Hand::parse(input, use_joker);
+    let mut games = Hand::parse(input, use_joker)?;
     games.sort();
 
     // println!("{:#?}", games);
@@ -57,3 +75,18 @@ pub fn cal_winning_points(input: &str, use_joker: bool) -> Result<TotalWinnings>
     Ok(winnings)
 }
 
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_winning_points(input, false)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_winning_points(input, true)?.into())
+    }
+}
@@ -19,6 +19,25 @@ ZZZ = (ZZZ, ZZZ)";
     assert_eq!(2, cal_steps(input).unwrap());
 }
 
+#[test]
+fn test_input_variants() {
+    let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+    crate::input::check_variants(input, cal_steps);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day8);
+}
+
 #[test]
 fn test_steps2() {
     let input = "LLR
@@ -46,6 +65,16 @@ XXX = (XXX, XXX)";
     assert_eq!(6, cal_steps_simultanious(input).unwrap());
 }
 
+#[test]
+fn test_invalid_network() {
+    let err = cal_steps("RXL\n\nAAA = (ZZZ, ZZZ)").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 1, column: 2, .. })));
+    let err = cal_steps("RL\n\nAAA = (ZZZ, ZZZ)\nZZZ = ZZZ, ZZZ").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 4, column: 7, .. })));
+    assert!(cal_steps("RL\n\nAAAA = (ZZZ, ZZZ)").is_err());
+    assert!(cal_steps("RL").is_err());
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Node([char; 3]);
 
@@ -70,10 +99,11 @@ impl Node {
     }
 }
 
-impl From<&str> for Node {
-    fn from(s: &str) -> Self {
+impl Node {
+    fn parse(source: &Source, s: &str) -> Result<Self> {
         let vec = s.chars().collect::<Vec<_>>();
-        Self(vec.as_slice().try_into().unwrap())
+        let name = vec.try_into().map_err(|_| source.error(s, "a node name of 3 chars"))?;
+        Ok(Self(name))
     }
 }
 
@@ -97,18 +127,18 @@ enum Instruction {
     Right,
 }
 
-impl From<char> for Instruction {
-    fn from(c: char) -> Self {
+impl Instruction {
+    fn parse(c: char) -> Option<Self> {
         match c {
-            'L' => Instruction::Left,
-            'R' => Instruction::Right,
-            _ => panic!("Invalid instruction: {}", c),
+            'L' => Some(Instruction::Left),
+            'R' => Some(Instruction::Right),
+            _ => None,
         }
     }
 }
 
 pub fn cal_steps(input: &str) -> Result<u64> {
-    let (instructions, networks) = parse_network(input);
+    let (instructions, networks) = parse_network(input)?;
     let mut node = Node::start();
 
     let needed_steps = instructions.into_iter()
@@ -140,7 +170,7 @@ fn cal_steps_needed(instructions: &[Instruction], networks: &HashMap<Node, Netwo
 }
 
 pub fn cal_steps_simultanious(input: &str) -> Result<u64> {
-    let (instructions, networks) = parse_network(input);
+    let (instructions, networks) = parse_network(input)?;
 
     let nodes = networks.keys()
     .filter(|node| node.is_start())
@@ -162,23 +192,42 @@ pub fn cal_steps_simultanious(input: &str) -> Result<u64> {
     Ok(lcm)
 }
 
-fn parse_network(input: &str) -> (Vec<Instruction>, HashMap<Node, Network>) {
-    let (instructions, networks) = input.split_once("\n\n").unwrap();    
-    let instructions = instructions.chars().map(Instruction::from).collect::<Vec<_>>();
+fn parse_network(input: &str) -> Result<(Vec<Instruction>, HashMap<Node, Network>)> {
+    let source = Source::new(8, input);
+    let (instructions, networks) = source.split_once(input, "\n\n")?;
+    let instructions = source.chars(instructions, "'L' or 'R'", Instruction::parse)?;
 
     let networks = networks.lines()
     .map(|line| {
-        let (src, dest) = line.split_once(" = ").unwrap();
-        let src = Node::from(src);
-        let dest = dest.replace(['(', ')'], "");
-        let (left, right) = dest.split_once(", ").unwrap();
-        let left = Node::from(left);
-        let right = Node::from(right);
-        (src, Network { left, right })
+        let (src, dest) = source.split_once(line, " = ")?;
+        let src = Node::parse(&source, src)?;
+        let dest = dest
+            .strip_prefix('(')
+            .and_then(|dest| dest.strip_suffix(')'))
+            .ok_or_else(|| source.error(dest, "(left, right)"))?;
+        let (left, right) = source.split_once(dest, ", ")?;
+        let left = Node::parse(&source, left)?;
+        let right = Node::parse(&source, right)?;
+        Ok((src, Network { left, right }))
     });
 
-    let networks = networks.collect::<HashMap<_, _>>();
+    let networks = networks.collect::<Result<HashMap<_, _>>>()?;
 
-    (instructions, networks)
+    Ok((instructions, networks))
 }
 
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_steps(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_steps_simultanious(input)?.into())
+    }
+}
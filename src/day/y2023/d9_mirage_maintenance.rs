@@ -13,11 +13,29 @@ fn test_next_steps() {
     assert_eq!(114, cal_next_steps(INPUT).unwrap());
 }
 
+#[test]
+fn test_input_variants() {
+    crate::input::check_variants(INPUT, cal_next_steps);
+}
+
+#[test]
+fn test_extracted_examples() {
+    crate::examples::check_examples(2023, &Day9);
+}
+
 #[test]
 fn test_prev_steps() {
     assert_eq!(2, cal_prev_steps(INPUT).unwrap());
 }
 
+#[test]
+fn test_invalid_history() {
+    let err = cal_next_steps("0 3 6\n1 3 x 10").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 5, .. })));
+    let err = cal_prev_steps("0 3 6\n\n1 3 6").unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 1, .. })));
+}
+
 type Value = i64;
 
 #[derive(Debug, Clone)]
@@ -94,23 +112,27 @@ impl Row {
     }
 }
 
-impl From<&str> for Row {
-    fn from(input: &str) -> Self {
-        let numbers = input.split(' ').map(|s| s.parse().unwrap()).collect();
-        Self (numbers) 
+impl Row {
+    fn parse(source: &Source, line: &str) -> Result<Self> {
+        let numbers = line.split_whitespace().map(|s| source.num(s)).collect::<Result<VecDeque<_>>>()?;
+        if numbers.is_empty() {
+            return Err(source.error(line, "at least one value"));
+        }
+        Ok(Self (numbers))
     }
 }
 
-fn parse_history(input: &str) -> Vec<History> {
-    input.lines().map(|line| {
-        let element = Row::from(line);
-        History::new(element)
+fn parse_history(input: &str) -> Result<Vec<History>> {
+    let source = Source::new(9, input);
+    source.lines().map(|line| {
+        let element = Row::parse(&source, line)?;
+        Ok(History::new(element))
     })
     .collect()
 }
 
 pub fn cal_next_steps(input: &str) -> Result<Value> {
-    let mut histories = parse_history(input);
+    let mut histories = parse_history(input)?;
 
     histories.iter_mut().for_each(|history| {
         history.predict_next();
@@ -124,7 +146,7 @@ pub fn cal_next_steps(input: &str) -> Result<Value> {
 }
 
 pub fn cal_prev_steps(input: &str) -> Result<Value> {
-    let mut histories = parse_history(input);
+    let mut histories = parse_history(input)?;
 
     histories.iter_mut().for_each(|history| {
         history.predict_prev();
@@ -136,3 +158,19 @@ pub fn cal_prev_steps(input: &str) -> Result<Value> {
 
     Ok(sum)
 }
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_next_steps(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_prev_steps(input)?.into())
+    }
+}
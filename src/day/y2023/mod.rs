@@ -0,0 +1,57 @@
+pub mod d1_trebuchet;
+pub mod d2_cube_conundrum;
+pub mod d3_gear_ratios;
+pub mod d4_scratchcards;
+pub mod d5_fertilizer;
+pub mod d6_wait_for_it;
+pub mod d7_camel_cards;
+pub mod d8_haunted_wasteland;
+pub mod d9_mirage_maintenance;
+pub mod d10_pipe_maze;
+pub mod d11_cosmic_expansion;
+pub mod d12_hot_springs;
+pub mod d13_point_of_incidence;
+pub mod d14_parabolic_reflector_dish;
+pub mod d15_lens_library;
+pub mod d16_the_floor_will_be_lava;
+pub mod d17_clumsy_crucible;
+pub mod d18_lavaduct_lagoon;
+pub mod d19_aplenty;
+pub mod d20_pulse_propagation;
+pub mod d21_step_counter;
+pub mod d22_sand_slabs;
+pub mod d23_a_long_walk;
+pub mod d24_never_tell_me_the_odds;
+pub mod d25_snowverload;
+
+use crate::prelude::*;
+
+pub fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(d1_trebuchet::Day1),
+        Box::new(d2_cube_conundrum::Day2),
+        Box::new(d3_gear_ratios::Day3),
+        Box::new(d4_scratchcards::Day4),
+        Box::new(d5_fertilizer::Day5),
+        Box::new(d6_wait_for_it::Day6),
+        Box::new(d7_camel_cards::Day7),
+        Box::new(d8_haunted_wasteland::Day8),
+        Box::new(d9_mirage_maintenance::Day9),
+        Box::new(d10_pipe_maze::Day10),
+        Box::new(d11_cosmic_expansion::Day11::default()),
+        Box::new(d12_hot_springs::Day12),
+        Box::new(d13_point_of_incidence::Day13),
+        Box::new(d14_parabolic_reflector_dish::Day14::default()),
+        Box::new(d15_lens_library::Day15),
+        Box::new(d16_the_floor_will_be_lava::Day16),
+        Box::new(d17_clumsy_crucible::Day17),
+        Box::new(d18_lavaduct_lagoon::Day18),
+        Box::new(d19_aplenty::Day19),
+        Box::new(d20_pulse_propagation::Day20),
+        Box::new(d21_step_counter::Day21::default()),
+        Box::new(d22_sand_slabs::Day22),
+        Box::new(d23_a_long_walk::Day23),
+        Box::new(d24_never_tell_me_the_odds::Day24::default()),
+        Box::new(d25_snowverload::Day25),
+    ]
+}
@@ -0,0 +1,300 @@
+//! Example inputs and their answers, pulled out of saved puzzle pages.
+//! Every `<pre><code>` block is an example, the last `<code><em>` of a part is its answer.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{day::DEFAULT_YEAR, prelude::*};
+
+#[allow(dead_code)]
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2023</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54388</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>For example:</p>
+<pre><code>two1nine
+eightwothree
+<em>x</em>&lt;y&gt; &amp; z
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+#[test]
+fn test_parse_page() {
+    let page = Page::parse(PAGE).unwrap();
+    assert_eq!(page.day, 1);
+    assert_eq!(page.examples, vec![
+        "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".to_owned(),
+        "two1nine\neightwothree\nx<y> & z\n".to_owned(),
+    ]);
+    assert_eq!(page.answers, vec![
+        ExampleAnswer { part: Part::One, example: 1, answer: "142".to_owned() },
+        ExampleAnswer { part: Part::Two, example: 2, answer: "281".to_owned() },
+    ]);
+    assert!(Page::parse("<html>maintenance</html>").is_err());
+}
+
+#[test]
+fn test_part_two_reuses_example() {
+    let html = PAGE.replace("<pre><code>two1nine\neightwothree\n<em>x</em>&lt;y&gt; &amp; z\n</code></pre>", "");
+    let page = Page::parse(&html).unwrap();
+    assert_eq!(page.examples.len(), 1);
+    assert_eq!(page.answers[1], ExampleAnswer { part: Part::Two, example: 1, answer: "281".to_owned() });
+}
+
+#[test]
+fn test_save_and_load() {
+    let dir = crate::testing::temp_path("examples");
+    let page = Page::parse(PAGE).unwrap();
+
+    let files = page.save(&dir, 2023).unwrap();
+    assert_eq!(files, vec![dir.join("1-1.txt"), dir.join("1-2.txt"), dir.join("1-answers.txt")]);
+    assert_eq!(fs::read_to_string(dir.join("1-answers.txt")).unwrap(), "1 1 142\n2 2 281\n");
+    assert_eq!(load(&dir, 2023, 1, 2).unwrap(), "two1nine\neightwothree\nx<y> & z");
+    assert_eq!(load_answer(&dir, 2023, 1, Part::Two).unwrap(), ("two1nine\neightwothree\nx<y> & z".to_owned(), "281".to_owned()));
+    assert!(load(&dir, 2023, 1, 3).is_none());
+
+    page.save(&dir, 2022).unwrap();
+    assert!(dir.join("2022").join("1-1.txt").exists());
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+pub const EXAMPLE_DIR: &str = "examples";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleAnswer {
+    pub part: Part,
+    /// starts at 1 like the file names
+    pub example: usize,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    pub day: u8,
+    pub examples: Vec<String>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+impl Page {
+    pub fn parse(html: &str) -> Result<Self> {
+        let day = html
+            .split_once("--- Day ")
+            .and_then(|(_, rest)| rest.split_once(':'))
+            .and_then(|(day, _)| day.trim().parse().ok())
+            .ok_or("no \"--- Day <n>:\" heading, is this a puzzle page?")?;
+
+        let mut examples = Vec::new();
+        let mut answers = Vec::new();
+        for (part, article) in articles(html).into_iter().take(2).enumerate() {
+            let mut answer = None;
+            for (tag, text) in tags(article) {
+                match tag {
+                    Tag::Example => examples.push(text),
+                    // only the last highlighted code is the answer
+                    Tag::Highlight if !examples.is_empty() => answer = Some((examples.len(), text)),
+                    Tag::Highlight => {}
+                }
+            }
+            if let Some((example, answer)) = answer {
+                let part = Part::try_from(part as u8 + 1)?;
+                answers.push(ExampleAnswer { part, example, answer: answer.trim().to_owned() });
+            }
+        }
+
+        if examples.is_empty() {
+            return Err(format!("no examples found for day {}", day).into());
+        }
+        Ok(Self { day, examples, answers })
+    }
+
+    /// Writes `<day>-<n>.txt` for every example and `<day>-answers.txt` with a `<part> <n> <answer>` line per part.
+    /// Returns the written files.
+    pub fn save(&self, dir: &Path, year: u16) -> Result<Vec<PathBuf>> {
+        let dir = year_dir(dir, year);
+        fs::create_dir_all(&dir)?;
+
+        let mut files = Vec::new();
+        for (n, example) in self.examples.iter().enumerate() {
+            let path = dir.join(format!("{}-{}.txt", self.day, n + 1));
+            fs::write(&path, example)?;
+            files.push(path);
+        }
+
+        if !self.answers.is_empty() {
+            let path = dir.join(format!("{}-answers.txt", self.day));
+            let content = self
+                .answers
+                .iter()
+                .map(|answer| format!("{} {} {}\n", answer.part, answer.example, answer.answer))
+                .collect::<String>();
+            fs::write(&path, content)?;
+            files.push(path);
+        }
+        Ok(files)
+    }
+}
+
+/// `examples/` for 2023, `examples/<year>/` for the other years
+fn year_dir(dir: &Path, year: u16) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        dir.to_owned()
+    } else {
+        dir.join(year.to_string())
+    }
+}
+
+/// Example `n` of a day, normalized like the real inputs
+#[cfg(test)]
+pub fn load(dir: &Path, year: u16, day: u8, n: usize) -> Option<String> {
+    let path = year_dir(dir, year).join(format!("{}-{}.txt", day, n));
+    crate::input::read_file(&path).ok()
+}
+
+/// The example of a part together with its expected answer
+#[cfg(test)]
+pub fn load_answer(dir: &Path, year: u16, day: u8, part: Part) -> Option<(String, String)> {
+    let answers = fs::read_to_string(year_dir(dir, year).join(format!("{}-answers.txt", day))).ok()?;
+    answers.lines().find_map(|line| {
+        let mut fields = line.splitn(3, ' ');
+        let line_part = Part::try_from(fields.next()?.parse::<u8>().ok()?).ok()?;
+        let example = fields.next()?.parse().ok()?;
+        let answer = fields.next()?;
+        if line_part != part {
+            return None;
+        }
+        Some((load(dir, year, day, example)?, answer.to_owned()))
+    })
+}
+
+/// Solves the examples `aoc examples` extracted for a day and compares them with their answers.
+/// Parts that aren't done are left out, without extracted examples there is nothing to check.
+#[cfg(test)]
+pub fn check_examples(year: u16, solution: &dyn Solution) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLE_DIR);
+    for part in enum_iterator::all::<Part>() {
+        if solution.status(part) != Status::Done {
+            continue;
+        }
+        if let Some((example, answer)) = load_answer(&dir, year, solution.day(), part) {
+            let solved = solution.solve(part, &example).unwrap();
+            assert_eq!(solved.to_string(), answer, "example of day {} part {}", solution.day(), part);
+        }
+    }
+}
+
+/// The `<article>`s of the page, one per part. Pages saved without them count as a single part.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+    while let Some((_, after)) = rest.split_once("<article") {
+        let Some((article, after)) = after.split_once("</article>") else {
+            break;
+        };
+        articles.push(article);
+        rest = after;
+    }
+    if articles.is_empty() {
+        articles.push(html);
+    }
+    articles
+}
+
+enum Tag {
+    /// `<pre><code>`
+    Example,
+    /// `<code><em>`
+    Highlight,
+}
+
+/// Examples and highlighted code in the order they appear, without markup
+fn tags(html: &str) -> Vec<(Tag, String)> {
+    let mut tags = Vec::new();
+    let mut rest = html;
+    loop {
+        let example = rest.find("<pre><code>");
+        let highlight = rest.find("<code><em>");
+        let (tag, start, open, close) = match (example, highlight) {
+            (Some(e), Some(h)) if e < h => (Tag::Example, e, "<pre><code>", "</code></pre>"),
+            (Some(e), None) => (Tag::Example, e, "<pre><code>", "</code></pre>"),
+            (_, Some(h)) => (Tag::Highlight, h, "<code><em>", "</em></code>"),
+            (None, None) => break,
+        };
+        let after = &rest[start + open.len()..];
+        let Some((inner, after)) = after.split_once(close) else {
+            break;
+        };
+        tags.push((tag, decode(&strip_tags(inner))));
+        rest = after;
+    }
+    tags
+}
+
+/// Examples highlight parts of themselves with `<em>`, only the text is wanted
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// The few entities the puzzle pages use, plus numeric ones
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let c = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                let number = entity.strip_prefix('#')?;
+                let code = match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => number.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
@@ -0,0 +1,214 @@
+//! Directions and positions shared by the grid puzzles, (0, 0) is the top left.
+
+use enum_iterator::Sequence;
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+fn any_direction() -> impl Strategy<Value = Direction> {
+    prop::sample::select(Direction::ALL.to_vec())
+}
+
+#[test]
+fn test_turns() {
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Left.opposite(), Direction::Right);
+    assert_eq!(Direction::Down.turn(Turn::Right), Direction::Left);
+    assert_eq!(enum_iterator::all::<Direction>().collect::<Vec<_>>(), Direction::ALL);
+}
+
+#[test]
+fn test_steps() {
+    let pos = Pos::new(0, 2);
+    assert_eq!(pos.step(Direction::Up), None);
+    assert_eq!(pos.step(Direction::Left), Some(Pos::new(0, 1)));
+    assert_eq!(pos.step_wrapping(Direction::Up, 3, 3), Pos::new(2, 2));
+    assert_eq!(pos.step_wrapping(Direction::Right, 3, 3), Pos::new(0, 0));
+    assert_eq!(IPos::new(0, 0).step(Direction::Up), IPos::new(-1, 0));
+    assert_eq!(IPos::new(-1, -4).wrap(3, 3), Pos::new(2, 2));
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_turns(dir in any_direction()) {
+        prop_assert_eq!(dir.turn_left().turn_right(), dir);
+        prop_assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+        prop_assert_eq!(dir.turn_left().turn_left().turn_left().turn_left(), dir);
+        prop_assert_eq!(dir.opposite().opposite(), dir);
+        prop_assert_ne!(dir.is_horizontal(), dir.turn_left().is_horizontal());
+    }
+
+    #[test]
+    fn prop_step_and_back(row in 0..1000usize, col in 0..1000usize, dir in any_direction()) {
+        let pos = Pos::new(row, col);
+        if let Some(next) = pos.step(dir) {
+            prop_assert_eq!(next.step(dir.opposite()), Some(pos));
+            prop_assert_eq!(pos.manhattan(&next), 1);
+        }
+        let signed = IPos::from(pos).step(dir);
+        prop_assert_eq!(Pos::try_from(signed).ok(), pos.step(dir));
+        prop_assert_eq!(signed.step(dir.opposite()), IPos::from(pos));
+    }
+
+    #[test]
+    fn prop_wrapping(row in 0..20usize, col in 0..20usize, dir in any_direction()) {
+        let (rows, cols) = (20, 20);
+        let pos = Pos::new(row, col);
+        let next = pos.step_wrapping(dir, rows, cols);
+        prop_assert_eq!(next.step_wrapping(dir.opposite(), rows, cols), pos);
+        prop_assert_eq!(IPos::from(pos).step(dir).wrap(rows, cols), next);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Sequence)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A quarter turn, as seen by someone walking in a direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+
+    /// (row, col) change of a single step
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+/// Row and column of a cell inside a grid
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// None if the result would be left of or above the origin
+    pub fn offset(&self, d_row: isize, d_col: isize) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+
+    /// None when walking off the top or left edge
+    pub fn step(&self, dir: Direction) -> Option<Self> {
+        let (d_row, d_col) = dir.delta();
+        self.offset(d_row, d_col)
+    }
+
+    /// Steps off one edge of a rows x cols area come back in on the other side
+    pub fn step_wrapping(&self, dir: Direction, rows: usize, cols: usize) -> Self {
+        IPos::from(*self).step(dir).wrap(rows, cols)
+    }
+
+    pub fn manhattan(&self, other: &Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+/// Like [Pos], but free to leave the grid in every direction
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IPos {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl IPos {
+    pub fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn step(&self, dir: Direction) -> Self {
+        self.step_by(dir, 1)
+    }
+
+    pub fn step_by(&self, dir: Direction, len: isize) -> Self {
+        let (d_row, d_col) = dir.delta();
+        Self::new(self.row + d_row * len, self.col + d_col * len)
+    }
+
+    /// Maps onto a rows x cols grid that repeats endlessly
+    pub fn wrap(&self, rows: usize, cols: usize) -> Pos {
+        Pos::new(
+            self.row.rem_euclid(rows as isize) as usize,
+            self.col.rem_euclid(cols as isize) as usize,
+        )
+    }
+
+    pub fn manhattan(&self, other: &Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<Pos> for IPos {
+    fn from(pos: Pos) -> Self {
+        Self::new(pos.row as isize, pos.col as isize)
+    }
+}
+
+impl TryFrom<IPos> for Pos {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(pos: IPos) -> std::result::Result<Self, Self::Error> {
+        Ok(Self::new(pos.row.try_into()?, pos.col.try_into()?))
+    }
+}
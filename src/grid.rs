@@ -0,0 +1,338 @@
+//! Character grids as used by most of the puzzles, stored row by row.
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Direction, IPos, Pos},
+    prelude::*,
+};
+
+#[allow(dead_code)]
+const INPUT: &str = "#..
+.#.
+..#
+#..";
+
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TestCell {
+    Wall,
+    Open,
+}
+
+#[cfg(test)]
+impl Cell for TestCell {
+    const EXPECTED: &'static str = "'#' or '.'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(TestCell::Wall),
+            '.' => Some(TestCell::Open),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            TestCell::Wall => '#',
+            TestCell::Open => '.',
+        }
+    }
+}
+
+#[test]
+fn test_parse_and_display() {
+    let grid = Grid::<TestCell>::parse(&Source::new(0, INPUT), INPUT).unwrap();
+    assert_eq!((grid.rows(), grid.cols()), (4, 3));
+    assert_eq!(grid[Pos::new(1, 1)], TestCell::Wall);
+    assert_eq!(grid.get(Pos::new(4, 0)), None);
+    assert_eq!(grid.to_string(), INPUT);
+
+    let walls = grid.iter().filter(|(_, cell)| **cell == TestCell::Wall).map(|(pos, _)| pos);
+    assert_eq!(walls.collect::<Vec<_>>(), vec![Pos::new(0, 0), Pos::new(1, 1), Pos::new(2, 2), Pos::new(3, 0)]);
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = "#..\n.x.";
+    let err = Grid::<TestCell>::parse(&Source::new(0, input), input).unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 2, .. })));
+
+    let input = "#..\n.#";
+    let err = Grid::<TestCell>::parse(&Source::new(0, input), input).unwrap_err();
+    assert!(matches!(err, AocError::Parse(ParseError { line: 2, column: 1, .. })));
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::new(3, 4, 0);
+    let corner = grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>();
+    assert_eq!(corner, vec![Pos::new(1, 0), Pos::new(0, 1)]);
+    assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbours8(Pos::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+    assert_eq!(grid.neighbours8(Pos::new(2, 3)).count(), 3);
+    assert_eq!(grid.step(Pos::new(2, 3), Direction::Right), None);
+    assert_eq!(grid.step(Pos::new(2, 3), Direction::Up), Some(Pos::new(1, 3)));
+    assert_eq!(grid.offset(Pos::new(2, 3), -1, -1), Some(Pos::new(1, 2)));
+}
+
+#[test]
+fn test_rotate_and_transpose() {
+    let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(grid.transpose(), Grid::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap());
+    assert_eq!(grid.rotate_cw(), Grid::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]).unwrap());
+    assert_eq!(grid.rotate_ccw(), Grid::from_rows(vec![vec![3, 6], vec![2, 5], vec![1, 4]]).unwrap());
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+}
+
+#[test]
+fn test_wrapping() {
+    let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(*grid.get_wrapping(IPos::new(0, 0)), 1);
+    assert_eq!(*grid.get_wrapping(IPos::new(-1, -1)), 6);
+    assert_eq!(*grid.get_wrapping(IPos::new(2, 4)), 2);
+    assert_eq!(*grid.get_wrapping(IPos::new(-7, 5)), 6);
+}
+
+/// A single cell that is read from and written as one char
+pub trait Cell: Sized {
+    /// Shown in parse errors for unknown chars
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    const EXPECTED: &'static str = "any char";
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+const NEIGHBOURS8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T: Cell> Grid<T> {
+    /// `text` has to be a slice of the source, so errors point at the right line
+    pub fn parse(source: &Source, text: &str) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = None;
+
+        for line in text.lines() {
+            let row = source.chars(line, T::EXPECTED, T::from_char)?;
+            match cols {
+                None => cols = Some(row.len()),
+                Some(cols) if cols != row.len() => {
+                    return Err(source.error(line, format!("{} cells like the first row", cols)))
+                }
+                Some(_) => {}
+            }
+            cells.extend(row);
+            rows += 1;
+        }
+
+        Ok(Self {
+            cells,
+            rows,
+            cols: cols.unwrap_or_default(),
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            cells: (0..self.cols).flat_map(|col| self.column(col).cloned()).collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Clockwise, the first column becomes the first row
+    pub fn rotate_cw(&self) -> Self {
+        let cells = (0..self.cols)
+            .flat_map(|col| self.column(col).rev().cloned())
+            .collect();
+        Self {
+            cells,
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Counterclockwise, the last column becomes the first row
+    pub fn rotate_ccw(&self) -> Self {
+        let cells = (0..self.cols)
+            .rev()
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+        Self {
+            cells,
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// None if the rows differ in length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        Some(Self {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.row * self.cols + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.cols + pos.col])
+        } else {
+            None
+        }
+    }
+
+    /// Toroidal access, the grid repeats endlessly in every direction
+    pub fn get_wrapping(&self, pos: IPos) -> &T {
+        &self[pos.wrap(self.rows, self.cols)]
+    }
+
+    /// The neighbouring position, if it is still inside the grid
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        pos.step(dir).filter(|pos| self.contains(*pos))
+    }
+
+    /// Like [Grid::step], for any distance and also diagonal
+    pub fn offset(&self, pos: Pos, d_row: isize, d_col: isize) -> Option<Pos> {
+        pos.offset(d_row, d_col).filter(|pos| self.contains(*pos))
+    }
+
+    /// Up, down, left and right, as far as they are inside the grid
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Including the diagonals
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8
+            .iter()
+            .filter_map(move |&(d_row, d_col)| self.offset(pos, d_row, d_col))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| Pos::new(row, col)))
+    }
+
+    /// All cells in row major order
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn rows_iter_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.cols.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.cols.max(1)).take(self.rows)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).expect("position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos).expect("position outside of the grid")
+    }
+}
+
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
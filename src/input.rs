@@ -0,0 +1,315 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use reqwest::{blocking, header};
+
+use crate::{day::DEFAULT_YEAR, prelude::*};
+
+#[cfg(test)]
+use crate::stub_server::StubServer;
+
+#[test]
+fn test_download_with_session() {
+    let server = StubServer::start(vec![(200, "1abc2\n")]);
+    let client = Client::new(&server.url, Some("abc123".to_owned()));
+
+    assert_eq!(client.fetch_input(2022, 3).unwrap(), "1abc2\n");
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /2022/day/3/input "));
+    assert!(requests[0].to_lowercase().contains("cookie: session=abc123"));
+}
+
+#[test]
+fn test_download_without_session() {
+    let client = Client::new("http://127.0.0.1:1", None);
+    assert!(client.fetch_input(2023, 3).is_err());
+}
+
+#[test]
+fn test_download_error_status() {
+    let server = StubServer::start(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.")]);
+    let client = Client::new(&server.url, Some("expired".to_owned()));
+    assert!(client.fetch_input(2023, 3).is_err());
+}
+
+#[test]
+fn test_rate_limit() {
+    let server = StubServer::start(vec![(200, "a"), (200, "b")]);
+    let client = Client::new(&server.url, Some("abc123".to_owned())).min_interval(Duration::from_millis(200));
+
+    let start = Instant::now();
+    client.fetch_input(2023, 1).unwrap();
+    client.fetch_input(2023, 2).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
+
+#[test]
+fn test_cache() {
+    let dir = crate::testing::temp_path("cache");
+    let server = StubServer::start(vec![(200, "downloaded\n")]);
+    let inputs = Inputs::new(&dir, 2023, Client::new(&server.url, Some("abc123".to_owned())));
+
+    // the cache keeps the raw download, days get it normalized
+    assert_eq!(inputs.load(4).unwrap(), "downloaded");
+    assert_eq!(fs::read_to_string(dir.join("2023").join("4.txt")).unwrap(), "downloaded\n");
+    // second load is served from disk, the stub would refuse another connection
+    assert_eq!(inputs.load(4).unwrap(), "downloaded");
+    assert_eq!(server.requests().len(), 1);
+
+    // 2023 inputs from before there were years are still found
+    fs::write(dir.join("5.txt"), "flat\n").unwrap();
+    assert_eq!(inputs.load(5).unwrap(), "flat");
+    assert_eq!(input_path(&dir, 2022, 5), dir.join("2022").join("5.txt"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_first_session() {
+    assert_eq!(first_session(["".to_owned(), " \n".to_owned(), "abc123\n".to_owned()]), Some("abc123".to_owned()));
+    assert_eq!(first_session(["".to_owned()]), None);
+}
+
+#[test]
+fn test_normalize() {
+    let input = "\u{feff}\r\n  \r\n  RL  \r\n\r\n  AAA = (BBB, CCC)\t\r\n\r\n";
+    assert_eq!(normalize(input), "RL\n\nAAA = (BBB, CCC)");
+    // leading whitespace that belongs to the input stays, like the crate stacks of 2022 day 5
+    let stacks = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n";
+    assert_eq!(normalize(stacks), "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3");
+    assert_eq!(normalize("\t  a\n\t b"), " a\nb");
+    assert_eq!(normalize("a\nb\n"), "a\nb");
+    assert_eq!(normalize(" \n\n"), "");
+    assert_eq!(normalize("\n  1 2 3  \n\n"), "  1 2 3");
+}
+
+#[test]
+fn test_single_line_variants() {
+    check_variants("  1 2 3", |input| Ok(input.to_owned()));
+}
+
+#[test]
+fn test_input_hash() {
+    assert_eq!(input_hash(""), "cbf29ce484222325");
+    assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    assert_ne!(input_hash("1abc2"), input_hash("1abc3"));
+}
+
+/// The same example as it might come from a download, an editor on windows or an indented test string.
+/// Every variant has to give the same result as the example once it went through [normalize].
+/// Single lines keep their indentation, so they aren't indented.
+#[cfg(test)]
+pub fn check_variants<T: PartialEq + std::fmt::Debug>(example: &str, solve: impl Fn(&str) -> Result<T>) {
+    let map_lines = |f: &dyn Fn(&str) -> String| example.lines().map(f).collect::<Vec<_>>().join("\n");
+    let mut variants = vec![
+        ("trailing newline", format!("{}\n", example)),
+        ("crlf", format!("{}\r\n", example.replace('\n', "\r\n"))),
+        ("bom", format!("\u{feff}{}", example)),
+        ("trailing whitespace", map_lines(&|line| format!("{} \t ", line))),
+        ("blank lines around", format!("\n\n  \n{}\n\n\n", example)),
+    ];
+    if example.lines().filter(|line| !line.trim().is_empty()).count() > 1 {
+        variants.push(("indented", map_lines(&|line| format!("        {}", line))));
+    }
+
+    let expected = solve(example).unwrap();
+    for (name, variant) in variants {
+        assert_eq!(solve(&normalize(&variant)).unwrap(), expected, "{} variant", name);
+    }
+}
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const INPUT_DIR: &str = "input";
+
+const SESSION_VAR: &str = "AOC_SESSION";
+const SESSION_FILE: &str = ".aoc-session";
+const USER_AGENT: &str = "github.com/yannick818/AOC";
+
+/// Session cookie from `$AOC_SESSION`, `./.aoc-session` or `~/.config/aoc/session`
+pub fn find_session() -> Option<String> {
+    let mut files = vec![PathBuf::from(SESSION_FILE)];
+    if let Some(home) = env::var_os("HOME") {
+        files.push(Path::new(&home).join(".config/aoc/session"));
+    }
+
+    // lazy, the files are only read if the variable isn't set
+    let files = files.into_iter().filter_map(|file| fs::read_to_string(file).ok());
+    first_session(env::var(SESSION_VAR).ok().into_iter().chain(files))
+}
+
+/// Empty sessions count as not set, the next place is tried then
+fn first_session(sessions: impl IntoIterator<Item = String>) -> Option<String> {
+    sessions
+        .into_iter()
+        .map(|session| session.trim().to_owned())
+        .find(|session| !session.is_empty())
+}
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    /// the site asks to not hammer it, so requests are spaced out
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            min_interval: Duration::from_secs(3),
+            last_request: Mutex::new(None),
+        }
+    }
+
+    pub fn min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(BASE_URL, find_session())
+    }
+
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            let waited = last.elapsed();
+            if waited < self.min_interval {
+                thread::sleep(self.min_interval - waited);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    fn send(&self, request: blocking::RequestBuilder) -> Result<String> {
+        let session = self.session.as_ref().ok_or(format!(
+            "no session token, set ${} or write it to {}",
+            SESSION_VAR, SESSION_FILE
+        ))?;
+
+        self.throttle();
+        let response = request
+            .header(header::COOKIE, format!("session={}", session))
+            .header(header::USER_AGENT, USER_AGENT)
+            .send()?
+            .error_for_status()?;
+        Ok(response.text()?)
+    }
+
+    pub fn get(&self, path: &str) -> Result<String> {
+        self.send(blocking::Client::new().get(format!("{}{}", self.base_url, path)))
+    }
+
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        self.send(blocking::Client::new().post(format!("{}{}", self.base_url, path)).form(form))
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    pub fn post_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String> {
+        let level = part.to_string();
+        self.post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &level), ("answer", answer)],
+        )
+    }
+}
+
+/// `<dir>/<year>/<day>.txt`. For 2023 a `<dir>/<day>.txt` from before there were years is used if it exists.
+pub fn input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    let flat = dir.join(format!("{}.txt", day));
+    if year == DEFAULT_YEAR && flat.exists() {
+        return flat;
+    }
+    dir.join(year.to_string()).join(format!("{}.txt", day))
+}
+
+/// Puzzle inputs of one year cached in `input/<year>/<day>.txt`, downloaded on first use
+pub struct Inputs {
+    dir: PathBuf,
+    year: u16,
+    client: Client,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>, year: u16, client: Client) -> Self {
+        Self {
+            dir: dir.into(),
+            year,
+            client,
+        }
+    }
+
+    pub fn for_year(year: u16) -> Self {
+        Self::new(INPUT_DIR, year, Client::from_env())
+    }
+
+    pub fn load(&self, day: u8) -> Result<String> {
+        let path = input_path(&self.dir, self.year, day);
+        if path.exists() {
+            return read_file(&path);
+        }
+
+        eprintln!("Downloading input for {} day {} to {}", self.year, day, path.display());
+        let input = self.client.fetch_input(self.year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, &input)?;
+        Ok(normalize(&input))
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String> {
+    Ok(normalize(&fs::read_to_string(path)?))
+}
+
+/// Short fingerprint of an input (64 bit FNV-1a), to tell which input an answer belongs to.
+/// Has to stay the same between runs and rust versions, so no `DefaultHasher`.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Drops a BOM, CRLF line endings, trailing whitespace, an indentation shared by all lines and blank lines around the input.
+/// Blank lines in between separate sections and are kept, leading whitespace of single lines is part of the input,
+/// also when the whole input is a single line.
+/// The days can then split on "\n\n" and don't have to care where the input came from.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let lines = input.lines().map(str::trim_end).collect::<Vec<_>>();
+    let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|line| !line.is_empty()).map_or(start, |end| end + 1);
+    let lines = &lines[start..end];
+
+    // e.g. from an indented test string, a single line has nothing to share its indentation with
+    let content = lines.iter().filter(|line| !line.is_empty());
+    let indent = if content.clone().count() > 1 {
+        content
+            .map(|line| &line[..line.len() - line.trim_start().len()])
+            .reduce(|common, indent| {
+                let shared = common.chars().zip(indent.chars()).take_while(|(a, b)| a == b).map(|(a, _)| a.len_utf8()).sum();
+                &common[..shared]
+            })
+            .unwrap_or_default()
+    } else {
+        ""
+    };
+    lines
+        .iter()
+        .map(|line| line.strip_prefix(indent).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
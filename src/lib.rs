@@ -0,0 +1,23 @@
+//! Advent of Code solutions as a library, the `aoc` binary is a CLI on top.
+//! `day` has the solutions of every year, `prelude` the error and result types they share.
+
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod day;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod prelude;
+#[cfg(test)]
+mod regression;
+pub mod scaffold;
+#[cfg(test)]
+mod stub_server;
+pub mod summary;
+#[cfg(test)]
+mod testing;
+pub mod visual;
@@ -1,156 +1,293 @@
-mod day;
-mod prelude;
-
-use crate::day::*;
-
-use crate::prelude::*;
-
-#[allow(unused_imports)]
-use std::time::Instant;
-use std::{fs::File, io::Read};
-
-macro_rules! measure {
-    ($title:expr, $func:expr) => {{
-        let start = Instant::now();
-        let result = $func;
-        let duration = start.elapsed();
-        let time = if duration.as_secs() / 60 > 1 {
-            format!("{} min", duration.as_secs())
-        } else if duration.as_secs() > 1 {
-            format!("{} s  ", duration.as_secs())
-        } else if duration.as_millis() > 1 {
-            format!("{} ms ", duration.as_millis())
-        } else if duration.as_micros() > 1 {
-            format!("{} us ", duration.as_micros())
+mod cli;
+
+use aoc::answers::*;
+use aoc::bench::*;
+use aoc::cancel::*;
+use aoc::day::*;
+use aoc::input::*;
+use aoc::summary::*;
+use aoc::{examples, scaffold, visual};
+use crate::cli::*;
+
+use aoc::prelude::*;
+
+use std::{fs, path::Path, sync::Arc, time::Duration};
+
+use clap::Parser;
+
+fn main() {
+    // Display instead of the Debug output of returning the error from main
+    if let Err(err) = try_main() {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+fn try_main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let year = cli.year;
+
+    if cli.list {
+        return list(year);
+    }
+
+    match cli.command {
+        Some(Command::Run(args)) => run(year, &args),
+        Some(Command::Submit { day, part, answer }) => submit_answer(year, day, part, answer),
+        Some(Command::Bench(args)) => run_bench(year, &args),
+        Some(Command::Fetch { days }) => fetch(year, &days),
+        Some(Command::Examples { page }) => extract_examples(year, &page),
+        Some(Command::NewDay { day, name }) => {
+            let path = scaffold::new_day(&scaffold::day_dir(), year, day, &name)?;
+            println!("Created {}", path.display());
+            Ok(())
+        }
+        None => run(year, &RunArgs::default()),
+    }
+}
+
+/// Solutions of the year, an error for years nobody solved here
+fn year_solutions(year: u16) -> Result<&'static [Box<dyn Solution>]> {
+    let solutions = solutions(year);
+    if solutions.is_empty() {
+        let years = years().map(|year| year.to_string()).collect::<Vec<_>>();
+        return Err(format!("no solutions for {}, there are some for {}", year, years.join(", ")).into());
+    }
+    Ok(solutions)
+}
+
+fn list(year: u16) -> Result<()> {
+    for solution in year_solutions(year)? {
+        let parts = enum_iterator::all::<Part>()
+            .filter_map(|part| match solution.status(part) {
+                Status::Done => Some(part.to_string()),
+                Status::Slow => Some(format!("{} (slow)", part)),
+                Status::Todo => None,
+            })
+            .collect::<Vec<_>>();
+
+        if parts.is_empty() {
+            println!("Day {:>2}: -", solution.day());
         } else {
-            format!("{} ns ", duration.as_nanos())
-        };
-        println!("Day {:>4} in {:>8}: {}", $title, time, result);
-    }};
+            println!("Day {:>2}: {}", solution.day(), parts.join(", "));
+        }
+    }
+    Ok(())
 }
 
-fn main() -> Result<()> {
-
-    let input = read_file("input/1.txt")?;
-    measure!("1.1", d1_trebuchet::cal_trebuchet(&input)?);
-    measure!("1.2", d1_trebuchet::cal_trebuchet_str(&input)?);
-
-    let input = read_file("input/2.txt")?;
-    measure!("2.1", d2_cube_conundrum::cal_cubes(&input)?);
-    measure!("2.2", d2_cube_conundrum::cal_cubes2(&input)?);
-
-    let input = read_file("input/3.txt")?;
-    measure!("3.1", d3_gear_ratios::cal_gear_ratio(&input)?);
-    measure!("3.2", d3_gear_ratios::cal_gear_ratio2(&input)?);
-
-    let input = read_file("input/4.txt")?;
-    measure!("4.1", d4_scratchcards::cal_card_points(&input)?);
-    measure!("4.2", d4_scratchcards::count_cards(&input)?);
-
-    let input = read_file("input/5.txt")?;
-    measure!("5.1", d5_fertilizer::cal_lowest_location(&input)?);
-    //30s with par_iter, 115s without on MacAir M1
-    // measure!("5.2", d5_fertilizer::cal_lowest_loc_ranges(&input)?);
-
-    let input = read_file("input/6.txt")?;
-    measure!("6.1", d6_wait_for_it::cal_ways_to_win(&input)?);
-    measure!("6.2", d6_wait_for_it::cal_ways_to_win2(&input)?);
-
-    let input = read_file("input/7.txt")?;
-    measure!("7.1", d7_camel_cards::cal_winning_points(&input, false)?); 
-    measure!("7.2", d7_camel_cards::cal_winning_points(&input, true)?); 
-
-    let input = read_file("input/8.txt")?;
-    measure!("8.1", d8_haunted_wasteland::cal_steps(&input)?); 
-    measure!("8.2", d8_haunted_wasteland::cal_steps_simultanious(&input)?); 
-
-    let input = read_file("input/9.txt")?;
-    measure!("9.1", d9_mirage_maintenance::cal_next_steps(&input)?); 
-    measure!("9.2", d9_mirage_maintenance::cal_prev_steps(&input)?); 
-
-    let input = read_file("input/10.txt")?;
-    measure!("10.1", d10_pipe_maze::cal_maze_distance(&input)?); 
-    measure!("10.2", d10_pipe_maze::cal_enclosed_tiles(&input)?); 
-
-    let input = read_file("input/11.txt")?;
-    measure!("11.1", d11_cosmic_expansion::cal_sum_of_paths(&input, 1)?); 
-    measure!("11.2", d11_cosmic_expansion::cal_sum_of_paths(&input, 1_000_000)?); 
-
-    let input = read_file("input/12.txt")?;
-    measure!("12.1", d12_hot_springs::cal_arrangement_sum(&input)?); 
-    measure!("12.2", d12_hot_springs::cal_arrangement_sum_folded(&input)?); 
-
-    let input = read_file("input/13.txt")?;
-    measure!("13.1", d13_point_of_incidence::cal_reflection_code(&input)?); 
-    measure!("13.2", d13_point_of_incidence::cal_reflection_code2(&input)?); 
-
-    let input = read_file("input/14.txt")?;
-    measure!("14.1", d14_parabolic_reflector_dish::cal_total_load(&input)?); 
-    measure!("14.2", d14_parabolic_reflector_dish::cal_load_after(&input, 1_000_000_000)?); 
-
-    let input = read_file("input/15.txt")?;
-    measure!("15.1", d15_lens_library::cal_hash_sum(&input)?); 
-    measure!("15.2", d15_lens_library::cal_focus_power(&input)?); 
-
-    let input = read_file("input/16.txt")?;
-    measure!("16.1", d16_the_floor_will_be_lava::cal_energized_tiles(&input)?); 
-    measure!("16.2", d16_the_floor_will_be_lava::cal_max_energized_tiles(&input)?); 
-
-    // let input = read_file("input/18.txt")?;
-    // println!("Day 18.1: {}", d18_lavaduct_lagoon::cal_trench_volume(&input)?); 
-    // println!("Day 18.2: {}", d16_the_floor_will_be_lava::cal_max_energized_tiles(&input)?); 
-
-    let input = read_file("input/19.txt")?;
-    measure!("19.1", d19_aplenty::cal_sum_accepted(&input)?); 
-    // measure!("19.2", d19_aplenty::cal_all_possibilities(&input)?); 
-
-    let input = read_file("input/20.txt")?;
-    measure!("20.1", d20_pulse_propagation::cal_pulses(&input)?);  
-    // measure!("20.2", d20_pulse_propagation::cal_rx_pushes(&input)?); 
-    
-    let input = read_file("input/21.txt")?;
-    measure!("21.1", d21_step_counter::cal_plot_count(&input, 64)?);  
-    // measure!("21.2", d21_step_counter::cal_plot_count_extending(&input, 26501365)?); 
-
-    let input = read_file("input/22.txt")?;
-    measure!("22.1", d22_sand_slabs::cal_loose_bricks(&input)?);  
-    measure!("22.2", d22_sand_slabs::cal_falling_bricks(&input)?); 
-
-    let input = read_file("input/23.txt")?;
-    measure!("23.1", d23_a_long_walk::cal_longest_hike(&input)?);  
-    //TODO improve this, it needs 60s on mac air m1
-    // measure!("23.2", d23_a_long_walk::cal_longest_hike_noslope(&input)?); 
-
-    let input = read_file("input/24.txt")?;
-    measure!("24.1", d24_never_tell_me_the_odds::cal_future_intersections(&input, 200_000_000_000_000..=400_000_000_000_000)?);  
-    // measure!("24.2", d22_sand_slabs::cal_falling_bricks(&input)?); 
-    
-    // let input = read_file("input/25.txt")?;
-    // measure!("25.1", d25_snowverload::cal_group_size(&input)?);  
-    // measure!("25.2", d21_step_counter::cal_plot_count_extending(&input, 26501365)?); 
+/// Parts of a day to run, slow parts only with --slow or when the day is picked explicitly
+fn selected_parts(solution: &dyn Solution, part: Option<Part>, days: &DaySelection, slow: bool) -> Vec<Part> {
+    enum_iterator::all::<Part>()
+        .filter(|p| part.is_none_or(|part| part == *p))
+        .filter(|p| match solution.status(*p) {
+            Status::Done => true,
+            Status::Slow => slow || days.is_single(),
+            Status::Todo => false,
+        })
+        .collect()
+}
 
+fn run(year: u16, args: &RunArgs) -> Result<()> {
+    if args.input.is_some() && !args.days.is_single() {
+        return Err("--input needs a single day".into());
+    }
+
+    handle_ctrl_c()?;
+    let mut answers = Answers::load(answers_file(year))?;
+    let (jobs, missing) = jobs(year, args, &answers)?;
+    let budget = args.timeout.map(Duration::from_secs);
+
+    if args.parallel && cfg!(feature = "alloc-stats") {
+        eprintln!("Warning: parts running side by side count each other's heap, run without -j for exact numbers");
+    }
+
+    let rows = if args.parallel {
+        let mut summary = run_parallel(jobs, budget);
+        summary.add(missing);
+        if args.format == Format::Text {
+            print!("{}", summary);
+        }
+        summary.rows
+    } else {
+        // the player clears the screen and owns the terminal until it is dropped, rows are printed after it then
+        let print_live = args.format == Format::Text && !args.visual;
+        if print_live {
+            for row in &missing {
+                println!("{}", row);
+            }
+        }
+        let mut sinks = Vec::new();
+        if let Some(path) = &args.record {
+            sinks.push(visual::recorder(path)?);
+        }
+        if args.visual {
+            sinks.push(Box::new(visual::Player::new()?) as Box<dyn visual::Sink>);
+        }
+
+        let run_jobs = || {
+            let mut rows = missing;
+            for job in jobs {
+                let row = job.run(budget);
+                if print_live {
+                    println!("{}", row);
+                }
+                rows.push(row);
+            }
+            rows
+        };
+        let mut rows = if sinks.is_empty() { run_jobs() } else { visual::watch(sinks, run_jobs)? };
+        if args.format == Format::Text && !print_live {
+            for row in &rows {
+                println!("{}", row);
+            }
+        }
+        rows.sort_by_key(|row| (row.day, row.part));
+        rows
+    };
+
+    match args.format {
+        Format::Text => {}
+        Format::Json => print!("{}", to_json(&rows)?),
+        Format::Csv => print!("{}", to_csv(&rows)),
+        Format::Markdown => print!("{}", to_markdown(&rows)),
+    }
+
+    // answers recorded before there were hashes get the hash of the input they are correct for
+    let mut hashed = false;
+    for row in rows.iter().filter(|row| row.status == RunStatus::Correct) {
+        if let Some(hash) = &row.input_hash {
+            hashed |= answers.add_input_hash(row.day, row.part, hash);
+        }
+    }
+    if hashed {
+        answers.save()?;
+    }
+
+    let failed = rows.iter().filter(|row| row.status == RunStatus::Failed).count();
+    if failed > 0 {
+        return Err(format!("{} of {} parts failed", failed, rows.len()).into());
+    }
     Ok(())
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let mut file = File::open(path)?;
-    let mut buffer = String::new();
-    file.read_to_string(&mut buffer)?;
-    Ok(buffer)
+/// Parts to run, and the ones that can't run because their input is missing
+fn jobs(year: u16, args: &RunArgs, answers: &Answers) -> Result<(Vec<Job>, Vec<RunResult>)> {
+    let solutions = year_solutions(year)?;
+    let inputs = Inputs::for_year(year);
+    let part = args.part.map(Part::try_from).transpose()?;
+    let days = args.days.days();
+
+    let mut jobs = Vec::new();
+    let mut missing = Vec::new();
+    for solution in solutions.iter().filter(|s| days.contains(&s.day())) {
+        let day = solution.day();
+        let parts = selected_parts(solution.as_ref(), part, &args.days, args.slow);
+        if parts.is_empty() {
+            continue;
+        }
+
+        // inputs are loaded up front, downloads should not happen in parallel
+        let input = match &args.input {
+            Some(path) => read_file(path),
+            None => inputs.load(day),
+        };
+        match input {
+            Ok(input) => {
+                let hash = input_hash(&input);
+                let stale = parts.iter().any(|part| answers.input_hash(day, *part).is_some_and(|recorded| recorded != hash));
+                if stale {
+                    eprintln!("Warning: the recorded answers for day {} belong to another input, they are not checked", day);
+                }
+
+                let input: Arc<str> = Arc::from(input);
+                jobs.extend(parts.into_iter().map(|part| Job {
+                    solution: solution.as_ref(),
+                    part,
+                    input: input.clone(),
+                    expected: answers.get(day, part).map(str::to_owned),
+                    expected_hash: answers.input_hash(day, part).map(str::to_owned),
+                }));
+            }
+            Err(err) => missing.extend(parts.into_iter().map(|part| RunResult::failed(day, part, &err))),
+        }
+    }
+
+    Ok((jobs, missing))
 }
 
-#[test]
-fn test_website() {
-    let path = "https://adventofcode.com/2023/day/3/input";
-    let input = read_website(path).unwrap();
-    // login needed...
-    println!("{}", input);
+fn run_bench(year: u16, args: &BenchArgs) -> Result<()> {
+    let solutions = year_solutions(year)?;
+    let inputs = Inputs::for_year(year);
+    let part = args.part.map(Part::try_from).transpose()?;
+    let days = args.days.days();
+    let baseline = args.baseline.clone().unwrap_or_else(|| baseline_file(year));
+    // days that are not benchmarked this time keep their old numbers when saving
+    let mut saved = Baseline::load(&baseline)?;
+
+    for solution in solutions.iter().filter(|s| days.contains(&s.day())) {
+        let parts = selected_parts(solution.as_ref(), part, &args.days, args.slow);
+        if parts.is_empty() {
+            continue;
+        }
+
+        let input = inputs.load(solution.day())?;
+        for part in parts {
+            let (_, stats) = bench(args.warmup, args.runs, || solution.solve(part, &input))?;
+            let change = match saved.compare(solution.day(), part, &stats) {
+                Some(change) => format!("{:+.1}%", change),
+                None => String::new(),
+            };
+            println!("Day {:>4}: {} {}", format!("{}.{}", solution.day(), part), stats, change);
+            saved.insert(solution.day(), part, stats);
+        }
+    }
+
+    if args.save {
+        saved.save(&baseline)?;
+        println!("Saved baseline to {}", baseline.display());
+    }
+    Ok(())
 }
 
-#[allow(dead_code)]
-fn read_website(url: &str) -> Result<String> {
-    let response = reqwest::blocking::get(url)?;
-    let input = response.text()?;
-    Ok(input)
+fn submit_answer(year: u16, day: u8, part: u8, answer: Option<String>) -> Result<()> {
+    let part = Part::try_from(part)?;
+    // also needed for a given answer, to remember which input it is for
+    let input = Inputs::for_year(year).load(day)?;
+    let answer = match answer {
+        Some(answer) => Answer::from(answer),
+        None => {
+            let solution = solution(year, day).ok_or(format!("day {} of {} is missing", day, year))?;
+            solution.solve(part, &input)?
+        }
+    };
+
+    let mut answers = Answers::load(answers_file(year))?;
+    if answers.check(day, part, &answer) == Some(true) {
+        println!("Day {}.{}: {} is already known to be correct", day, part, answer);
+        return Ok(());
+    }
+
+    let verdict = submit(&Client::from_env(), &mut answers, year, day, part, &answer, Some(&input_hash(&input)))?;
+    println!("Day {}.{}: {} is {}", day, part, answer, verdict);
+    Ok(())
 }
 
+fn fetch(year: u16, days: &DaySelection) -> Result<()> {
+    let inputs = Inputs::for_year(year);
+    for day in days.days() {
+        inputs.load(day)?;
+    }
+    Ok(())
+}
+
+fn extract_examples(year: u16, page: &Path) -> Result<()> {
+    let page = examples::Page::parse(&fs::read_to_string(page)?)?;
+    for file in page.save(Path::new(examples::EXAMPLE_DIR), year)? {
+        println!("Wrote {}", file.display());
+    }
+    if page.answers.is_empty() {
+        println!("No answers found for day {}", page.day);
+    }
+    Ok(())
+}
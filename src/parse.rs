@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::prelude::*;
+
+#[allow(dead_code)]
+const INPUT: &str = "seeds: 79 14
+seed-to-soil map:
+50 98 x";
+
+#[test]
+fn test_error_position() {
+    let source = Source::new(5, INPUT);
+    let x = &INPUT[INPUT.len() - 1..];
+    let AocError::Parse(err) = source.error(x, "a number") else {
+        panic!("not a parse error");
+    };
+    assert_eq!(err, ParseError {
+        day: 5,
+        line: 3,
+        column: 7,
+        text: "x".to_owned(),
+        expected: "a number".to_owned(),
+    });
+    assert_eq!(err.to_string(), "day 5, line 3, column 7: expected a number, found \"x\"");
+}
+
+#[test]
+fn test_source_helpers() {
+    let source = Source::new(5, INPUT);
+    let first = source.lines().next().unwrap();
+    let (_, seeds) = source.split_once(first, ": ").unwrap();
+    let seeds = seeds.split(' ').map(|s| source.num(s)).collect::<Result<Vec<u64>>>().unwrap();
+    assert_eq!(seeds, vec![79, 14]);
+
+    let last = source.lines().last().unwrap();
+    assert!(source.split_once(last, " -> ").is_err());
+    assert!(source.num::<u64>(&last[6..]).is_err());
+
+    let digits = source.chars(&first[7..9], "a digit", |c| c.to_digit(10)).unwrap();
+    assert_eq!(digits, vec![7, 9]);
+    let AocError::Parse(err) = source.chars(first, "a digit", |c| c.to_digit(10)).unwrap_err() else {
+        panic!("not a parse error");
+    };
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, 1, "s"));
+}
+
+/// Where and why a puzzle input could not be parsed, line and column start at 1
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("day {day}, line {line}, column {column}: expected {expected}, found {text:?}")]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+/// The whole input of a day, so errors in any slice of it can be reported with line and column
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Self { day, input }
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.input.lines()
+    }
+
+    /// `text` has to be a slice of the input, otherwise the position is unknown and reported as 0
+    pub fn error(&self, text: &str, expected: impl Into<String>) -> AocError {
+        let offset = (text.as_ptr() as usize).wrapping_sub(self.input.as_ptr() as usize);
+        let (line, column) = if offset <= self.input.len() && self.input.is_char_boundary(offset) {
+            let before = &self.input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+        } else {
+            (0, 0)
+        };
+
+        AocError::Parse(ParseError {
+            day: self.day,
+            line,
+            column,
+            text: text.to_owned(),
+            expected: expected.into(),
+        })
+    }
+
+    pub fn split_once(&self, text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, format!("{:?}", delimiter)))
+    }
+
+    pub fn num<T: FromStr>(&self, text: &str) -> Result<T> {
+        text.trim().parse().map_err(|_| self.error(text, "a number"))
+    }
+
+    /// Parses every char of `text` with `parse`, None is reported as `expected`
+    pub fn chars<T>(&self, text: &'a str, expected: &str, parse: impl Fn(char) -> Option<T>) -> Result<Vec<T>> {
+        text.char_indices()
+            .map(|(i, c)| parse(c).ok_or_else(|| self.error(&text[i..i + c.len_utf8()], expected)))
+            .collect()
+    }
+}
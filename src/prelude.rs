@@ -11,10 +11,28 @@ pub enum AocError {
     IO(#[from] std::io::Error),
 
     #[error("Parse Error: {0}")]
-    Parse(#[from] std::num::ParseIntError),
+    ParseInt(#[from] std::num::ParseIntError),
+
+    #[error("Parse Error: {0}")]
+    Parse(#[from] ParseError),
 
     #[error("Request Error: {0}")]
     Request(#[from] reqwest::Error),
+
+    #[error("JSON Error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("GIF Error: {0}")]
+    Gif(#[from] gif::EncodingError),
+
+    #[error("timed out after {} s", .0.as_secs_f64())]
+    Timeout(std::time::Duration),
+
+    #[error("cancelled")]
+    Cancelled,
+
+    #[error("panicked: {0}")]
+    Panic(String),
 }
 
 impl std::convert::From<&str> for AocError {
@@ -31,3 +49,6 @@ impl std::convert::From<String> for AocError {
 
 pub type Result<T> = std::result::Result<T, AocError>;
 
+
+pub use crate::day::{Answer, Part, Solution, Status};
+pub use crate::parse::{ParseError, Source};
@@ -0,0 +1,60 @@
+//! Checks every implemented part against the real inputs in `input/<year>/` and the recorded answers of that year.
+//! Missing inputs or answers are skipped, slow parts only run with `AOC_SLOW` set.
+//! `AOC_YEAR` limits the check to a single year.
+
+use std::{env, path::Path};
+
+use crate::{
+    answers::{answers_file, Answers},
+    day::{solutions, years},
+    input::{input_hash, input_path, read_file, INPUT_DIR},
+    prelude::*,
+};
+
+#[test]
+fn test_real_inputs() {
+    let slow = env::var_os("AOC_SLOW").is_some();
+    let only_year = env::var("AOC_YEAR").ok().map(|year| year.parse::<u16>().expect("AOC_YEAR is no year"));
+    let mut failures = Vec::new();
+
+    for year in years().filter(|year| only_year.is_none_or(|only| only == *year)) {
+        check_year(year, slow, &mut failures);
+    }
+
+    assert!(failures.is_empty(), "wrong answers:\n{}", failures.join("\n"));
+}
+
+fn check_year(year: u16, slow: bool, failures: &mut Vec<String>) {
+    let answers = Answers::load(answers_file(year)).unwrap();
+
+    for solution in solutions(year) {
+        let day = solution.day();
+        let Ok(input) = read_file(&input_path(Path::new(INPUT_DIR), year, day)) else {
+            continue;
+        };
+
+        for part in enum_iterator::all::<Part>() {
+            let Some(expected) = answers.get(day, part) else {
+                continue;
+            };
+            if answers.input_hash(day, part).is_some_and(|hash| hash != input_hash(&input)) {
+                eprintln!("skipping {} day {}.{}, the answer is for another input", year, day, part);
+                continue;
+            }
+            match solution.status(part) {
+                Status::Done => {}
+                Status::Slow if slow => {}
+                Status::Slow | Status::Todo => {
+                    eprintln!("skipping {} day {}.{}", year, day, part);
+                    continue;
+                }
+            }
+
+            match solution.solve(part, &input) {
+                Ok(answer) if answer.to_string() == expected => {}
+                Ok(answer) => failures.push(format!("{} day {}.{}: {} != {}", year, day, part, answer, expected)),
+                Err(err) => failures.push(format!("{} day {}.{}: {}", year, day, part, err)),
+            }
+        }
+    }
+}
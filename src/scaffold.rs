@@ -0,0 +1,287 @@
+//! Generates the module for a new day and registers it in `src/day/y<year>/mod.rs`.
+//! The first day of a new year also creates the year module and registers it in `src/day/mod.rs`.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::prelude::*;
+
+#[allow(dead_code)]
+const YEAR_MOD_RS: &str = "pub mod d1_trebuchet;
+pub mod d3_gear_ratios;
+
+use crate::prelude::*;
+
+pub fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(d1_trebuchet::Day1),
+        Box::new(d3_gear_ratios::Day3),
+    ]
+}
+";
+
+#[allow(dead_code)]
+const DAY_MOD_RS: &str = "pub mod y2023;
+
+pub const DEFAULT_YEAR: u16 = 2023;
+
+const YEARS: &[(u16, YearSolutions)] = &[
+    (2023, y2023::solutions),
+];
+";
+
+#[test]
+fn test_module_name() {
+    assert_eq!(module_name(7, "Camel Cards").unwrap(), "d7_camel_cards");
+    assert_eq!(module_name(16, "The Floor Will Be Lava").unwrap(), "d16_the_floor_will_be_lava");
+    assert_eq!(module_name(24, "Never Tell Me The Odds!").unwrap(), "d24_never_tell_me_the_odds");
+    assert!(module_name(1, " - ").is_err());
+}
+
+#[test]
+fn test_register_day() {
+    let registered = register_day(YEAR_MOD_RS, 2, "d2_cube_conundrum").unwrap();
+    assert!(registered.contains("pub mod d1_trebuchet;\npub mod d2_cube_conundrum;\npub mod d3_gear_ratios;\n"));
+    assert!(registered.contains(
+        "Box::new(d1_trebuchet::Day1),\n        Box::new(d2_cube_conundrum::Day2),\n        Box::new(d3_gear_ratios::Day3),"
+    ));
+
+    let registered = register_day(YEAR_MOD_RS, 12, "d12_hot_springs").unwrap();
+    assert!(registered.contains("pub mod d3_gear_ratios;\npub mod d12_hot_springs;\n"));
+    assert!(registered.contains("Box::new(d3_gear_ratios::Day3),\n        Box::new(d12_hot_springs::Day12),\n    ]"));
+
+    assert!(register_day(YEAR_MOD_RS, 3, "d3_other_name").is_err());
+}
+
+#[test]
+fn test_register_year() {
+    let registered = register_year(DAY_MOD_RS, 2022).unwrap();
+    assert!(registered.starts_with("pub mod y2022;\npub mod y2023;\n"));
+    assert!(registered.contains("    (2022, y2022::solutions),\n    (2023, y2023::solutions),\n];"));
+    assert!(register_year(DAY_MOD_RS, 2023).is_err());
+}
+
+#[test]
+fn test_new_day() {
+    let dir = crate::testing::temp_path("new-day");
+    fs::create_dir_all(dir.join("y2023")).unwrap();
+    fs::write(dir.join("mod.rs"), DAY_MOD_RS).unwrap();
+    fs::write(dir.join("y2023").join("mod.rs"), YEAR_MOD_RS).unwrap();
+
+    let path = new_day(&dir, 2023, 2, "Cube Conundrum").unwrap();
+    assert_eq!(path, dir.join("y2023").join("d2_cube_conundrum.rs"));
+    let module = fs::read_to_string(&path).unwrap();
+    assert!(module.contains("pub struct Day2;"));
+    assert!(module.contains("        2\n"));
+    assert!(module.contains("#[ignore = \"part 1 is not solved yet\"]"));
+    assert!(module.contains("check_examples(2023, &Day2);"));
+    let year_mod_rs = fs::read_to_string(dir.join("y2023").join("mod.rs")).unwrap();
+    assert!(year_mod_rs.contains("Box::new(d2_cube_conundrum::Day2),"));
+
+    // neither the module nor the registry are touched a second time
+    fs::write(&path, "solved").unwrap();
+    assert!(new_day(&dir, 2023, 2, "Cube Conundrum").is_err());
+    assert!(new_day(&dir, 2023, 2, "Other Name").is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "solved");
+
+    // the first day of a year brings the year along
+    let path = new_day(&dir, 2022, 1, "Calorie Counting").unwrap();
+    assert_eq!(path, dir.join("y2022").join("d1_calorie_counting.rs"));
+    let year_mod_rs = fs::read_to_string(dir.join("y2022").join("mod.rs")).unwrap();
+    assert!(year_mod_rs.starts_with("pub mod d1_calorie_counting;\n"));
+    assert!(year_mod_rs.contains("        Box::new(d1_calorie_counting::Day1),\n"));
+    assert!(fs::read_to_string(dir.join("mod.rs")).unwrap().contains("(2022, y2022::solutions),"));
+
+    // a failing day leaves no year behind that would not compile
+    fs::create_dir_all(dir.join("y2021")).unwrap();
+    fs::write(dir.join("y2021").join("d1_sonar_sweep.rs"), "solved").unwrap();
+    assert!(new_day(&dir, 2021, 1, "Sonar Sweep").is_err());
+    assert!(!dir.join("y2021").join("mod.rs").exists());
+    assert!(!fs::read_to_string(dir.join("mod.rs")).unwrap().contains("y2021"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+/// Where the years live when running through cargo
+pub fn day_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("day")
+}
+
+const TEMPLATE: &str = r#"use crate::prelude::*;
+
+#[allow(dead_code)]
+const INPUT: &str = "TODO: example input from the puzzle";
+
+#[test]
+#[ignore = "part 1 is not solved yet"]
+fn test_part1() {
+    // TODO: expected answer from the puzzle, then remove the ignore
+    assert_eq!(0, cal_part1(INPUT).unwrap());
+}
+
+#[test]
+#[ignore = "part 2 is not solved yet"]
+fn test_part2() {
+    // TODO: expected answer from the puzzle, then remove the ignore
+    assert_eq!(0, cal_part2(INPUT).unwrap());
+}
+
+#[test]
+fn test_extracted_examples() {
+    // checks the parts once they are done and `aoc examples` saved the puzzle's examples
+    crate::examples::check_examples({year}, &Day{day});
+}
+
+pub fn cal_part1(_input: &str) -> Result<usize> {
+    Err("part 1 is not solved yet".into())
+}
+
+pub fn cal_part2(_input: &str) -> Result<usize> {
+    Err("part 2 is not solved yet".into())
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    fn day(&self) -> u8 {
+        {day}
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(cal_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        Ok(cal_part2(input)?.into())
+    }
+
+    // remove once the parts are solved, until then `run` skips them
+    fn status(&self, _part: Part) -> Status {
+        Status::Todo
+    }
+}
+"#;
+
+/// `mod.rs` of a year that is started with `module` as its first day
+const YEAR_TEMPLATE: &str = "pub mod {module};
+
+use crate::prelude::*;
+
+pub fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new({module}::Day{day}),
+    ]
+}
+";
+
+/// `d7_camel_cards` for day 7 "Camel Cards"
+pub fn module_name(day: u8, name: &str) -> Result<String> {
+    let words = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    if words.is_empty() {
+        return Err(format!("{:?} is no usable module name", name).into());
+    }
+    Ok(format!("d{}_{}", day, words.join("_")))
+}
+
+/// Creates `y<year>/d<day>_<name>.rs` in `dir` and registers it, `dir` being `src/day`.
+/// Refuses to touch anything if the day already has a module.
+pub fn new_day(dir: &Path, year: u16, day: u8, name: &str) -> Result<PathBuf> {
+    let module = module_name(day, name)?;
+    let year_dir = dir.join(format!("y{}", year));
+    let year_mod_rs = year_dir.join("mod.rs");
+    let new_year = !year_mod_rs.exists();
+
+    // a new year is registered in `src/day/mod.rs`, with the day as its only entry
+    let (registry, registered) = if new_year {
+        let day_mod_rs = dir.join("mod.rs");
+        let registered = register_year(&fs::read_to_string(&day_mod_rs)?, year)?;
+        (day_mod_rs, registered)
+    } else {
+        let registered = register_day(&fs::read_to_string(&year_mod_rs)?, day, &module)?;
+        (year_mod_rs.clone(), registered)
+    };
+
+    let path = year_dir.join(format!("{}.rs", module));
+    if year_dir.join(&module).exists() {
+        return Err(format!("{} already exists", year_dir.join(&module).display()).into());
+    }
+    // the day first, so a failure never leaves a registered module without its file.
+    // create_new fails if the file exists, so nothing gets overwritten
+    fs::create_dir_all(&year_dir)?;
+    let mut file = OpenOptions::new().write(true).create_new(true).open(&path)?;
+    let template = TEMPLATE.replace("{day}", &day.to_string()).replace("{year}", &year.to_string());
+    file.write_all(template.as_bytes())?;
+
+    if new_year {
+        let year_module = YEAR_TEMPLATE.replace("{module}", &module).replace("{day}", &day.to_string());
+        fs::write(&year_mod_rs, year_module)?;
+    }
+    fs::write(registry, registered)?;
+    Ok(path)
+}
+
+/// The day of `pub mod d7_camel_cards;` or `Box::new(d7_camel_cards::Day7),`
+fn day_key(line: &str) -> Option<u16> {
+    let line = line.trim();
+    let module = line.strip_prefix("pub mod ").or_else(|| line.strip_prefix("Box::new("))?;
+    let (day, _) = module.strip_prefix('d')?.split_once('_')?;
+    day.parse().ok()
+}
+
+/// The year of `pub mod y2023;` or `(2023, y2023::solutions),`
+fn year_key(line: &str) -> Option<u16> {
+    let line = line.trim();
+    if let Some(module) = line.strip_prefix("pub mod y") {
+        return module.strip_suffix(';')?.parse().ok();
+    }
+    let (year, _) = line.strip_prefix('(')?.split_once(',')?;
+    year.parse().ok()
+}
+
+/// Adds the `pub mod` line and the registry entry of a day, both sorted by day
+fn register_day(mod_rs: &str, day: u8, module: &str) -> Result<String> {
+    register(mod_rs, day_key, day as u16, &format!("pub mod {};", module), &format!("Box::new({}::Day{}),", module, day))
+        .ok_or_else(|| format!("day {} already has a module", day).into())
+}
+
+/// Adds the `pub mod` line and the `YEARS` entry of a year, both sorted by year
+fn register_year(mod_rs: &str, year: u16) -> Result<String> {
+    register(mod_rs, year_key, year, &format!("pub mod y{};", year), &format!("({}, y{}::solutions),", year, year))
+        .ok_or_else(|| format!("year {} already has a module", year).into())
+}
+
+/// None if `key` is registered already
+fn register(mod_rs: &str, key_of: fn(&str) -> Option<u16>, key: u16, module: &str, entry: &str) -> Option<String> {
+    let mut lines = mod_rs.lines().map(str::to_owned).collect::<Vec<_>>();
+    let is_mod = |line: &String| line.starts_with("pub mod ") && key_of(line).is_some();
+    let is_entry = |line: &String| !line.starts_with("pub mod ") && key_of(line).is_some();
+
+    if lines.iter().any(|line| is_mod(line) && key_of(line) == Some(key)) {
+        return None;
+    }
+
+    // both kinds of lines are in the file already, the new one goes before the first bigger key
+    let insert = |lines: &mut Vec<String>, is_kind: &dyn Fn(&String) -> bool, new_line: String| {
+        let at = lines
+            .iter()
+            .position(|line| is_kind(line) && key_of(line).is_some_and(|k| k > key))
+            .or_else(|| lines.iter().rposition(is_kind).map(|last| last + 1))
+            .unwrap_or(lines.len());
+        lines.insert(at, new_line);
+    };
+
+    insert(&mut lines, &is_mod, module.to_owned());
+    let indent = lines.iter().find(|line| is_entry(line)).map_or(String::new(), |line| {
+        line[..line.len() - line.trim_start().len()].to_owned()
+    });
+    insert(&mut lines, &is_entry, format!("{}{}", indent, entry));
+
+    Some(lines.join("\n") + "\n")
+}
@@ -0,0 +1,66 @@
+//! Minimal HTTP server for tests, answers every connection with the next canned response.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::mpsc::{channel, Receiver},
+    thread,
+};
+
+pub struct StubServer {
+    pub url: String,
+    requests: Receiver<String>,
+}
+
+impl StubServer {
+    /// (status code, body) per expected request
+    pub fn start(responses: Vec<(u16, &str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_owned()))
+            .collect::<Vec<_>>();
+        let (sender, requests) = channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_len = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_len = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_len];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8_lossy(&content));
+
+                let response = format!(
+                    "HTTP/1.1 {} STUB\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                sender.send(request).unwrap();
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// raw requests received so far, including headers
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.try_iter().collect()
+    }
+}
@@ -0,0 +1,464 @@
+//! Results of running parts, as a table for the terminal or as json, csv or markdown for scripts.
+
+use std::{fmt::Display, sync::Arc, time::Duration};
+
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    alloc::{format_bytes, AllocStats},
+    bench::{format_duration, time},
+    cancel::run_part,
+    input::input_hash,
+    prelude::*,
+};
+
+#[cfg(test)]
+use crate::testing::TestDay;
+
+#[cfg(test)]
+fn test_rows() -> Vec<RunResult> {
+    let row = |day, answer: Result<Answer>, millis, expected: Option<&str>| {
+        let hash = answer.is_ok().then(|| "00000000000000ff".to_owned());
+        RunResult::new(day, Part::One, answer, Duration::from_millis(millis), expected.map(str::to_owned), hash)
+    };
+    vec![
+        row(1, Ok(Answer::from(142_u32)), 12, Some("142")),
+        row(10, Ok(Answer::from("a, \"b\"".to_owned())), 1500, Some("8")),
+        row(11, Err("bad input".into()), 0, None),
+    ]
+}
+
+#[test]
+fn test_run_parallel() {
+    let input: Arc<str> = Arc::from("abc");
+    let job = |part, expected: Option<&str>, expected_hash: Option<String>| Job {
+        solution: &TestDay,
+        part,
+        input: input.clone(),
+        expected: expected.map(str::to_owned),
+        expected_hash,
+    };
+
+    let jobs = vec![
+        job(Part::One, Some("3"), Some(input_hash("abc"))),
+        Job { input: Arc::from("panic"), ..job(Part::Two, None, None) },
+        job(Part::One, Some("4"), None),
+        job(Part::One, Some("4"), Some(input_hash("abcd"))),
+    ];
+    let summary = run_parallel(jobs, None);
+    assert_eq!(summary.rows[0].answer, Ok(Answer::from(3_usize)));
+    assert_eq!(summary.rows[0].status, RunStatus::Correct);
+    assert_eq!(summary.rows[0].input_hash, Some(input_hash("abc")));
+    assert_eq!(summary.rows[1].answer, Err("panicked: boom".to_owned()));
+    assert_eq!(summary.rows[1].status, RunStatus::Failed);
+    assert_eq!(summary.rows[2].status, RunStatus::Wrong);
+    // recorded for another input, so not wrong either
+    assert_eq!(summary.rows[3].status, RunStatus::Stale);
+    assert!(summary.rows[3].to_string().ends_with(": 3 ? (4 was recorded for another input)"));
+}
+
+#[test]
+fn test_table() {
+    let summary = Summary {
+        rows: test_rows(),
+        wall: Duration::from_millis(1502),
+    };
+
+    let expected = "\
+Day   Time    Check         Answer
+1.1   12 ms   ✓             142
+10.1  1 s     ✗ expected 8  a, \"b\"
+11.1  0 ns                  error: AocError: bad input
+3 parts, 1 failed, wall time 1 s, sum of part times 1 s
+";
+    assert_eq!(summary.to_string(), expected);
+
+    let mut summary = summary;
+    summary.rows[0].alloc = Some(AllocStats { allocations: 3, peak_bytes: 2048 });
+    summary.rows[1].alloc = Some(AllocStats { allocations: 1, peak_bytes: 1 << 20 });
+    let table = summary.to_string();
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "Day   Time    Peak     Allocs  Check         Answer");
+    assert_eq!(lines[1], "1.1   12 ms   2.0 KiB  3       ✓             142");
+    assert_eq!(lines[5], "most heap: 10.1 (1.0 MiB), 1.1 (2.0 KiB)");
+
+    let budget = Duration::from_secs(1);
+    summary.rows.push(RunResult::new(12, Part::One, Err(AocError::Timeout(budget)), budget, None, None));
+    summary.rows.push(RunResult::new(13, Part::One, Err(AocError::Cancelled), budget, None, None));
+    let table = summary.to_string();
+    assert!(table.contains("\n5 parts, 1 failed, 1 timed out, 1 cancelled, wall time 1 s"));
+}
+
+#[test]
+fn test_formats() {
+    let mut rows = test_rows();
+    rows[0].alloc = Some(AllocStats { allocations: 3, peak_bytes: 2048 });
+
+    let json: serde_json::Value = serde_json::from_str(&to_json(&rows).unwrap()).unwrap();
+    assert_eq!(json[0]["day"], 1);
+    assert_eq!(json[0]["answer"], "142");
+    assert_eq!(json[0]["status"], "correct");
+    assert_eq!(json[0]["duration_ns"], 12_000_000);
+    assert_eq!(json[0]["input_hash"], "00000000000000ff");
+    assert_eq!(json[0]["peak_bytes"], 2048);
+    assert_eq!(json[1]["allocations"], serde_json::Value::Null);
+    assert_eq!(json[2]["answer"], serde_json::Value::Null);
+    assert_eq!(json[2]["error"], "AocError: bad input");
+
+    let csv = to_csv(&rows);
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "day,part,status,answer,expected,duration_ns,peak_bytes,allocations,input_hash,error");
+    assert_eq!(lines[1], "1,1,correct,142,142,12000000,2048,3,00000000000000ff,");
+    assert_eq!(lines[2], "10,1,wrong,\"a, \"\"b\"\"\",8,1500000000,,,00000000000000ff,");
+    assert_eq!(lines[3], "11,1,failed,,,0,,,,AocError: bad input");
+
+    let markdown = to_markdown(&rows);
+    let lines = markdown.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "| Day | Part | Answer | Time | Status | Hash |");
+    assert_eq!(lines[1], "|----:|-----:|--------|-----:|--------|------|");
+    assert_eq!(lines[2], "| 1 | 1 | 142 | 12 ms | correct | 00000000000000ff |");
+    assert_eq!(lines[4], "| 11 | 1 | AocError: bad input | 0 ns | failed |  |");
+}
+
+/// One part to run, `expected` is the recorded answer if there is one
+pub struct Job {
+    pub solution: &'static dyn Solution,
+    pub part: Part,
+    pub input: Arc<str>,
+    pub expected: Option<String>,
+    /// hash of the input `expected` was recorded for
+    pub expected_hash: Option<String>,
+}
+
+impl Job {
+    pub fn run(self, budget: Option<Duration>) -> RunResult {
+        let (answer, duration, alloc) = run_part(self.solution, self.part, self.input.clone(), budget);
+        let hash = input_hash(&self.input);
+        let stale = self.expected_hash.as_ref().is_some_and(|expected| *expected != hash);
+
+        let mut row = RunResult::new(self.solution.day(), self.part, answer, duration, self.expected, Some(hash));
+        // an answer for another input says nothing about this one
+        if stale && matches!(row.status, RunStatus::Correct | RunStatus::Wrong) {
+            row.status = RunStatus::Stale;
+        }
+        row.alloc = alloc;
+        row
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    Correct,
+    Wrong,
+    /// no recorded answer to compare with
+    Unchecked,
+    /// the recorded answer is for another input
+    Stale,
+    Failed,
+    Timeout,
+    Cancelled,
+}
+
+impl Display for RunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            RunStatus::Correct => "correct",
+            RunStatus::Wrong => "wrong",
+            RunStatus::Unchecked => "unchecked",
+            RunStatus::Stale => "stale",
+            RunStatus::Failed => "failed",
+            RunStatus::Timeout => "timeout",
+            RunStatus::Cancelled => "cancelled",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RunResult {
+    pub day: u8,
+    pub part: Part,
+    /// errors and panics as text, so one broken day doesn't stop the others
+    pub answer: std::result::Result<Answer, String>,
+    pub status: RunStatus,
+    pub duration: Duration,
+    pub expected: Option<String>,
+    /// None if the input couldn't be loaded
+    pub input_hash: Option<String>,
+    /// only with the `alloc-stats` feature
+    pub alloc: Option<AllocStats>,
+}
+
+impl RunResult {
+    pub fn new(
+        day: u8,
+        part: Part,
+        answer: Result<Answer>,
+        duration: Duration,
+        expected: Option<String>,
+        input_hash: Option<String>,
+    ) -> Self {
+        let status = match (&answer, &expected) {
+            (Ok(answer), Some(expected)) if answer.to_string() == *expected => RunStatus::Correct,
+            (Ok(_), Some(_)) => RunStatus::Wrong,
+            (Ok(_), None) => RunStatus::Unchecked,
+            (Err(AocError::Timeout(_)), _) => RunStatus::Timeout,
+            (Err(AocError::Cancelled), _) => RunStatus::Cancelled,
+            (Err(_), _) => RunStatus::Failed,
+        };
+
+        Self {
+            day,
+            part,
+            answer: answer.map_err(|err| err.to_string()),
+            status,
+            duration,
+            expected,
+            input_hash,
+            alloc: None,
+        }
+    }
+
+    /// A part that didn't get to run, e.g. because its input is missing
+    pub fn failed(day: u8, part: Part, error: &AocError) -> Self {
+        Self {
+            day,
+            part,
+            answer: Err(error.to_string()),
+            status: RunStatus::Failed,
+            duration: Duration::ZERO,
+            expected: None,
+            input_hash: None,
+            alloc: None,
+        }
+    }
+
+    fn id(&self) -> String {
+        format!("{}.{}", self.day, self.part)
+    }
+}
+
+/// One line in the terminal while running one part after the other
+impl Display for RunResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let answer = match &self.answer {
+            Ok(answer) => answer,
+            Err(err) => return write!(f, "Day {:>4} {}", self.id(), err),
+        };
+
+        let duration = format_duration(self.duration);
+        match self.alloc {
+            Some(alloc) => write!(
+                f,
+                "Day {:>4} in {:>8}, {:>9} peak, {:>8} allocs",
+                self.id(),
+                duration.trim_end(),
+                format_bytes(alloc.peak_bytes),
+                alloc.allocations
+            )?,
+            None => write!(f, "Day {:>4} in {:>8}", self.id(), duration)?,
+        }
+        write!(f, ": {}", answer)?;
+
+        let expected = self.expected.as_deref().unwrap_or_default();
+        match self.status {
+            RunStatus::Correct => write!(f, " ✓"),
+            RunStatus::Wrong => write!(f, " ✗ (expected {})", expected),
+            RunStatus::Stale => write!(f, " ? ({} was recorded for another input)", expected),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub struct Summary {
+    pub rows: Vec<RunResult>,
+    pub wall: Duration,
+}
+
+impl Summary {
+    /// Time spent in the parts themselves, more than the wall time when they ran side by side
+    pub fn cpu(&self) -> Duration {
+        self.rows.iter().map(|row| row.duration).sum()
+    }
+
+    pub fn add(&mut self, rows: impl IntoIterator<Item = RunResult>) {
+        self.rows.extend(rows);
+        self.rows.sort_by_key(|row| (row.day, row.part));
+    }
+}
+
+/// Runs every job on the rayon pool, the rows keep the order of the jobs
+pub fn run_parallel(jobs: Vec<Job>, budget: Option<Duration>) -> Summary {
+    let (rows, wall) = time(|| jobs.into_par_iter().map(|job| job.run(budget)).collect());
+    Summary { rows, wall }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // heap columns only with the alloc-stats feature, answers last, errors can get long
+        let alloc = self.rows.iter().any(|row| row.alloc.is_some());
+        let header = if alloc {
+            vec!["Day", "Time", "Peak", "Allocs", "Check", "Answer"]
+        } else {
+            vec!["Day", "Time", "Check", "Answer"]
+        };
+        let header = header.into_iter().map(str::to_owned).collect::<Vec<_>>();
+        let cells = self
+            .rows
+            .iter()
+            .map(|row| {
+                let (answer, check) = match (&row.answer, row.status) {
+                    (Err(err), _) => (format!("error: {}", err), String::new()),
+                    (Ok(answer), RunStatus::Correct) => (answer.to_string(), "✓".to_owned()),
+                    (Ok(answer), RunStatus::Wrong) => (
+                        answer.to_string(),
+                        format!("✗ expected {}", row.expected.as_deref().unwrap_or_default()),
+                    ),
+                    (Ok(answer), RunStatus::Stale) => (answer.to_string(), "? other input".to_owned()),
+                    (Ok(answer), _) => (answer.to_string(), String::new()),
+                };
+                let mut line = vec![row.id(), format_duration(row.duration)];
+                if alloc {
+                    line.push(row.alloc.map(|alloc| format_bytes(alloc.peak_bytes)).unwrap_or_default());
+                    line.push(row.alloc.map(|alloc| alloc.allocations.to_string()).unwrap_or_default());
+                }
+                line.extend([check, answer]);
+                line
+            })
+            .collect::<Vec<_>>();
+
+        let mut widths = vec![0; header.len()];
+        for line in std::iter::once(&header).chain(&cells) {
+            for (width, cell) in widths.iter_mut().zip(line) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for line in std::iter::once(&header).chain(&cells) {
+            let line = line
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        // timeouts and Ctrl-C are no failure of the part, the exit code doesn't count them either
+        let count = |status| self.rows.iter().filter(|row| row.status == status).count();
+        write!(f, "{} parts, {} failed", self.rows.len(), count(RunStatus::Failed))?;
+        for (status, name) in [(RunStatus::Timeout, "timed out"), (RunStatus::Cancelled, "cancelled")] {
+            if count(status) > 0 {
+                write!(f, ", {} {}", count(status), name)?;
+            }
+        }
+        writeln!(
+            f,
+            ", wall time {}, sum of part times {}",
+            format_duration(self.wall).trim_end(),
+            format_duration(self.cpu()).trim_end()
+        )?;
+
+        let mut hogs = self.rows.iter().filter_map(|row| Some((row, row.alloc?))).collect::<Vec<_>>();
+        if !hogs.is_empty() {
+            hogs.sort_by_key(|(_, alloc)| std::cmp::Reverse(alloc.peak_bytes));
+            let hogs = hogs
+                .iter()
+                .take(3)
+                .map(|(row, alloc)| format!("{} ({})", row.id(), format_bytes(alloc.peak_bytes)))
+                .collect::<Vec<_>>();
+            writeln!(f, "most heap: {}", hogs.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Flat version of [RunResult], the same fields for json and csv
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: String,
+    status: RunStatus,
+    answer: Option<String>,
+    expected: Option<&'a str>,
+    duration_ns: u128,
+    peak_bytes: Option<usize>,
+    allocations: Option<u64>,
+    input_hash: Option<&'a str>,
+    error: Option<&'a str>,
+}
+
+impl<'a> From<&'a RunResult> for Record<'a> {
+    fn from(row: &'a RunResult) -> Self {
+        Self {
+            day: row.day,
+            part: row.part.to_string(),
+            status: row.status,
+            answer: row.answer.as_ref().ok().map(|answer| answer.to_string()),
+            expected: row.expected.as_deref(),
+            duration_ns: row.duration.as_nanos(),
+            peak_bytes: row.alloc.map(|alloc| alloc.peak_bytes),
+            allocations: row.alloc.map(|alloc| alloc.allocations),
+            input_hash: row.input_hash.as_deref(),
+            error: row.answer.as_ref().err().map(|err| err.as_str()),
+        }
+    }
+}
+
+pub fn to_json(rows: &[RunResult]) -> Result<String> {
+    let records = rows.iter().map(Record::from).collect::<Vec<_>>();
+    Ok(serde_json::to_string_pretty(&records)? + "\n")
+}
+
+pub fn to_csv(rows: &[RunResult]) -> String {
+    // quoted only when needed, quotes inside are doubled
+    let field = |value: &str| {
+        if value.contains([',', '"', '\n']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_owned()
+        }
+    };
+
+    let mut csv = "day,part,status,answer,expected,duration_ns,peak_bytes,allocations,input_hash,error\n".to_owned();
+    for record in rows.iter().map(Record::from) {
+        let line = [
+            record.day.to_string(),
+            record.part,
+            record.status.to_string(),
+            record.answer.unwrap_or_default(),
+            record.expected.unwrap_or_default().to_owned(),
+            record.duration_ns.to_string(),
+            record.peak_bytes.map(|bytes| bytes.to_string()).unwrap_or_default(),
+            record.allocations.map(|allocations| allocations.to_string()).unwrap_or_default(),
+            record.input_hash.unwrap_or_default().to_owned(),
+            record.error.unwrap_or_default().to_owned(),
+        ];
+        csv += &line.iter().map(|value| field(value)).collect::<Vec<_>>().join(",");
+        csv += "\n";
+    }
+    csv
+}
+
+/// Ready to paste into the README
+pub fn to_markdown(rows: &[RunResult]) -> String {
+    let mut markdown = "| Day | Part | Answer | Time | Status | Hash |\n|----:|-----:|--------|-----:|--------|------|\n".to_owned();
+    for row in rows {
+        let answer = match &row.answer {
+            Ok(answer) => answer.to_string(),
+            Err(err) => err.clone(),
+        };
+        markdown += &format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            row.day,
+            row.part,
+            answer.replace('|', "\\|"),
+            format_duration(row.duration).trim_end(),
+            row.status,
+            row.input_hash.as_deref().unwrap_or_default()
+        );
+    }
+    markdown
+}
@@ -0,0 +1,33 @@
+//! Fixtures shared by the unit tests.
+
+use std::{env, path::PathBuf, process, thread, time::Duration};
+
+use crate::{cancel::check, prelude::*};
+
+/// Part 1 is the length of the input, part 2 panics for "panic" and otherwise runs until it gets cancelled
+pub struct TestDay;
+
+impl Solution for TestDay {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        Ok(Answer::from(input.len()))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        if input == "panic" {
+            panic!("boom");
+        }
+        loop {
+            check()?;
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
+
+/// A file or dir in the temp dir that no other test run uses, `name` tells the tests apart
+pub fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("aoc-test-{}-{}", process::id(), name))
+}
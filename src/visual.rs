@@ -0,0 +1,470 @@
+//! Watching the grid days work, frame by frame, in the terminal or recorded to a file.
+//! Days report frames with [frame], which is a single atomic load while nobody watches.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use termion::raw::{IntoRawMode, RawTerminal};
+
+use crate::{
+    grid::{Cell, Grid},
+    prelude::*,
+};
+
+#[cfg(test)]
+struct Collect(std::sync::Arc<Mutex<Vec<Frame>>>);
+
+#[cfg(test)]
+impl Sink for Collect {
+    fn show(&mut self, frame: &Frame) -> Result<Control> {
+        self.0.lock().unwrap().push(frame.clone());
+        Ok(Control::Continue)
+    }
+}
+
+#[test]
+fn test_watch() {
+    let frames = std::sync::Arc::new(Mutex::new(Vec::new()));
+    let answer = watch(vec![Box::new(Collect(frames.clone()))], || {
+        for i in 0..3 {
+            frame(|| Frame::new(format!("test_watch {}", i), "#.\n.#".to_owned()));
+        }
+        42
+    })
+    .unwrap();
+    assert_eq!(answer, 42);
+
+    // other tests may report frames at the same time
+    let titles = frames
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|frame| frame.title.starts_with("test_watch"))
+        .map(|frame| frame.title.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["test_watch 0", "test_watch 1", "test_watch 2"]);
+}
+
+#[test]
+fn test_cast() {
+    let mut cast = Vec::new();
+    let mut recorder = CastRecorder::new(&mut cast);
+    recorder.show(&Frame::new("first", "#.\n.#".to_owned())).unwrap();
+    recorder.show(&Frame::new("second", "..\n##".to_owned())).unwrap();
+    drop(recorder);
+
+    let cast = String::from_utf8(cast).unwrap();
+    let lines = cast.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    let header: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!((header["version"].as_u64(), header["width"].as_u64(), header["height"].as_u64()), (Some(2), Some(5), Some(3)));
+    assert_eq!(lines[1], r#"[0.0,"o","\u001b[H\u001b[2Jfirst\r\n#.\r\n.#"]"#);
+    assert!(lines[2].starts_with("[0.1,"));
+}
+
+#[test]
+fn test_gif() {
+    let mut gif = Vec::new();
+    let mut recorder = GifRecorder::new(&mut gif);
+    recorder.show(&Frame::new("first", "#.\n.#".to_owned())).unwrap();
+    // bigger frames are cut to the size of the first one
+    recorder.show(&Frame::new("second", "O.O\n.#.\nOOO".to_owned())).unwrap();
+    drop(recorder);
+
+    assert!(gif.starts_with(b"GIF89a"));
+    // width and height of 2x2 cells
+    assert_eq!(gif[6..10], [8, 0, 8, 0]);
+    assert_eq!(gif.last(), Some(&0x3b));
+}
+
+/// A picture of the state, `text` is the grid with one line per row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub title: String,
+    pub text: String,
+}
+
+impl Frame {
+    pub fn new(title: impl Display, text: String) -> Self {
+        Self {
+            title: title.to_string(),
+            text,
+        }
+    }
+
+    pub fn grid<T: Cell>(title: impl Display, grid: &Grid<T>) -> Self {
+        Self::new(title, grid.to_string())
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    /// Columns and rows, including the title line
+    fn size(&self) -> (usize, usize) {
+        let cols = self.rows().chain([self.title.as_str()]).map(|row| row.chars().count()).max().unwrap_or(0);
+        (cols, self.rows().count() + 1)
+    }
+}
+
+pub enum Control {
+    Continue,
+    /// the sink doesn't want any more frames
+    Stop,
+}
+
+pub trait Sink: Send {
+    fn show(&mut self, frame: &Frame) -> Result<Control>;
+}
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static SINKS: Mutex<Vec<Box<dyn Sink>>> = Mutex::new(Vec::new());
+
+/// Reports a frame to everyone watching, `render` only runs if someone does.
+/// Blocks while the player is paused, the part is slowed down to the speed it is shown with.
+pub fn frame(render: impl FnOnce() -> Frame) {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let frame = render();
+    let mut sinks = SINKS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    sinks.retain_mut(|sink| match sink.show(&frame) {
+        Ok(Control::Continue) => true,
+        Ok(Control::Stop) => false,
+        Err(err) => {
+            eprintln!("Stopped showing frames: {}", err);
+            false
+        }
+    });
+    if sinks.is_empty() {
+        ACTIVE.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Shows the frames reported while `func` runs, the sinks are finished (files written, terminal restored) after it
+pub fn watch<T>(sinks: Vec<Box<dyn Sink>>, func: impl FnOnce() -> T) -> Result<T> {
+    *SINKS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = sinks;
+    ACTIVE.store(true, Ordering::Relaxed);
+
+    let result = func();
+
+    ACTIVE.store(false, Ordering::Relaxed);
+    // dropping finishes them
+    SINKS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clear();
+    Ok(result)
+}
+
+/// Recorder for `path`, a GIF for `.gif` and an asciinema cast otherwise
+pub fn recorder(path: &Path) -> Result<Box<dyn Sink>> {
+    let file = BufWriter::new(File::create(path)?);
+    if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("gif")) {
+        Ok(Box::new(GifRecorder::new(file)))
+    } else {
+        Ok(Box::new(CastRecorder::new(file)))
+    }
+}
+
+/// Recordings stop after this many frames, some days report millions of them
+const MAX_RECORDED: usize = 5000;
+/// Time between recorded frames
+const RECORDED_DELAY: Duration = Duration::from_millis(100);
+
+/// asciinema cast v2, a JSON header and one output event per frame.
+/// The size is taken from the first frame, the header can only be written then.
+pub struct CastRecorder<W: Write + Send> {
+    out: W,
+    frames: usize,
+}
+
+impl<W: Write + Send> CastRecorder<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, frames: 0 }
+    }
+}
+
+impl<W: Write + Send> Sink for CastRecorder<W> {
+    fn show(&mut self, frame: &Frame) -> Result<Control> {
+        if self.frames == 0 {
+            let (cols, rows) = frame.size();
+            let header = serde_json::json!({"version": 2, "width": cols, "height": rows});
+            writeln!(self.out, "{}", header)?;
+        }
+
+        let mut screen = format!("\x1b[H\x1b[2J{}", frame.title);
+        for row in frame.rows() {
+            screen += "\r\n";
+            screen += row;
+        }
+        let time = (self.frames as u32 * RECORDED_DELAY).as_secs_f64();
+        writeln!(self.out, "{}", serde_json::to_string(&(time, "o", screen))?)?;
+
+        self.frames += 1;
+        if self.frames == MAX_RECORDED {
+            eprintln!("Recorded {} frames, that's enough", MAX_RECORDED);
+            return Ok(Control::Stop);
+        }
+        Ok(Control::Continue)
+    }
+}
+
+impl<W: Write + Send> Drop for CastRecorder<W> {
+    fn drop(&mut self) {
+        let _ = self.out.flush();
+    }
+}
+
+/// Every cell is a square of this many pixels
+const GIF_CELL: usize = 4;
+/// Background for '.' and ' ', the rest gets the colors in the order they show up
+const GIF_BACKGROUND: [u8; 3] = [15, 15, 35];
+const GIF_COLORS: [[u8; 3]; 12] = [
+    [120, 120, 130],
+    [255, 255, 102],
+    [255, 102, 102],
+    [102, 204, 255],
+    [102, 255, 153],
+    [255, 178, 102],
+    [204, 153, 255],
+    [255, 255, 255],
+    [0, 153, 153],
+    [204, 0, 102],
+    [153, 102, 51],
+    [51, 102, 204],
+];
+
+/// Animated GIF, one colored square per cell. The title is left out.
+pub struct GifRecorder<W: Write + Send> {
+    out: Option<W>,
+    encoder: Option<gif::Encoder<W>>,
+    size: (usize, usize),
+    colors: HashMap<char, u8>,
+    frames: usize,
+}
+
+impl<W: Write + Send> GifRecorder<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out: Some(out),
+            encoder: None,
+            size: (0, 0),
+            colors: HashMap::new(),
+            frames: 0,
+        }
+    }
+
+    fn color(&mut self, c: char) -> u8 {
+        if c == '.' || c == ' ' {
+            return 0;
+        }
+        let next = self.colors.len();
+        // the palette has room for 256 colors, after that they are shared
+        *self.colors.entry(c).or_insert((next % 255) as u8 + 1)
+    }
+
+    fn palette(&self) -> Vec<u8> {
+        let mut palette = GIF_BACKGROUND.to_vec();
+        for i in 0..self.colors.len().min(255) {
+            palette.extend(GIF_COLORS[i % GIF_COLORS.len()]);
+        }
+        palette
+    }
+}
+
+impl<W: Write + Send> Sink for GifRecorder<W> {
+    fn show(&mut self, frame: &Frame) -> Result<Control> {
+        if let Some(out) = self.out.take() {
+            let cols = frame.rows().map(|row| row.chars().count()).max().unwrap_or(0).max(1);
+            let rows = frame.rows().count().max(1);
+            self.size = (cols, rows);
+            let (width, height) = ((cols * GIF_CELL) as u16, (rows * GIF_CELL) as u16);
+            let mut encoder = gif::Encoder::new(out, width, height, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            self.encoder = Some(encoder);
+        }
+
+        // cells outside of the first frame's size are cut off
+        let (cols, rows) = self.size;
+        let mut cells = vec![0_u8; cols * rows];
+        for (row, line) in frame.rows().take(rows).enumerate() {
+            for (col, c) in line.chars().take(cols).enumerate() {
+                cells[row * cols + col] = self.color(c);
+            }
+        }
+        let mut pixels = Vec::with_capacity(cells.len() * GIF_CELL * GIF_CELL);
+        for row in cells.chunks(cols) {
+            for _ in 0..GIF_CELL {
+                for cell in row {
+                    pixels.extend([*cell; GIF_CELL]);
+                }
+            }
+        }
+
+        let gif_frame = gif::Frame {
+            width: (cols * GIF_CELL) as u16,
+            height: (rows * GIF_CELL) as u16,
+            delay: (RECORDED_DELAY.as_millis() / 10) as u16,
+            palette: Some(self.palette()),
+            buffer: pixels.into(),
+            ..Default::default()
+        };
+        if let Some(encoder) = &mut self.encoder {
+            encoder.write_frame(&gif_frame)?;
+        }
+
+        self.frames += 1;
+        if self.frames == MAX_RECORDED {
+            eprintln!("Recorded {} frames, that's enough", MAX_RECORDED);
+            return Ok(Control::Stop);
+        }
+        Ok(Control::Continue)
+    }
+}
+
+const KEYS: &str = "space pause, n step, + faster, - slower, q stop watching";
+const PLAYER_DELAY: Duration = Duration::from_millis(100);
+/// How often keys are read while waiting
+const KEY_POLL: Duration = Duration::from_millis(10);
+
+/// Shows the frames in the terminal, which is in raw mode to get single key presses.
+/// Keys are read on their own thread, so Ctrl-C works while no frame is shown too.
+pub struct Player {
+    terminal: Arc<Mutex<RawTerminal<io::Stdout>>>,
+    keys: Receiver<u8>,
+    reader: Option<JoinHandle<()>>,
+    stop: Arc<AtomicBool>,
+    delay: Duration,
+    paused: bool,
+}
+
+impl Player {
+    pub fn new() -> Result<Self> {
+        let mut terminal = io::stdout().into_raw_mode()?;
+        write!(terminal, "{}", termion::cursor::Hide)?;
+        let terminal = Arc::new(Mutex::new(terminal));
+        let stop = Arc::new(AtomicBool::new(false));
+        let (sender, keys) = mpsc::channel();
+
+        let (thread_terminal, thread_stop) = (terminal.clone(), stop.clone());
+        let reader = thread::Builder::new().name("player keys".to_owned()).spawn(move || {
+            let mut stdin = termion::async_stdin();
+            while !thread_stop.load(Ordering::Relaxed) {
+                let mut keys = Vec::new();
+                if stdin.read_to_end(&mut keys).is_err() {
+                    return;
+                }
+                for key in keys {
+                    if key == 3 {
+                        interrupt(&thread_terminal);
+                    }
+                    let _ = sender.send(key);
+                }
+                thread::sleep(KEY_POLL);
+            }
+        })?;
+
+        Ok(Self {
+            terminal,
+            keys,
+            reader: Some(reader),
+            stop,
+            delay: PLAYER_DELAY,
+            paused: false,
+        })
+    }
+
+    fn draw(&mut self, frame: &Frame) -> Result<()> {
+        let (cols, rows) = termion::terminal_size().unwrap_or((80, 24));
+        let (cols, rows) = (cols as usize, rows as usize);
+        let state = if self.paused { "paused".to_owned() } else { format!("{} ms per frame", self.delay.as_millis()) };
+
+        let mut screen = format!("{}{}", termion::cursor::Goto(1, 1), termion::clear::All);
+        screen += &format!("{}  [{}]\r\n{}", frame.title, state, KEYS);
+        // big grids are cut to the terminal
+        for row in frame.rows().take(rows.saturating_sub(3)) {
+            screen += "\r\n";
+            screen.extend(row.chars().take(cols));
+        }
+        let mut terminal = self.terminal.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        write!(terminal, "{}", screen)?;
+        terminal.flush()?;
+        Ok(())
+    }
+
+    /// Reacts to the keys pressed so far, Stop for q
+    fn read_keys(&mut self) -> Result<(Control, bool)> {
+        let mut step = false;
+        let keys = self.keys.try_iter().collect::<Vec<_>>();
+        for key in keys {
+            match key {
+                b' ' => self.paused = !self.paused,
+                b'n' | b'.' => step = true,
+                b'+' => self.delay = (self.delay / 2).max(Duration::from_millis(1)),
+                b'-' => self.delay = (self.delay * 2).min(Duration::from_secs(10)),
+                b'q' => return Ok((Control::Stop, step)),
+                // the key thread already passed it on
+                3 => return Ok((Control::Stop, step)),
+                _ => {}
+            }
+        }
+        Ok((Control::Continue, step))
+    }
+}
+
+impl Sink for Player {
+    fn show(&mut self, frame: &Frame) -> Result<Control> {
+        self.draw(frame)?;
+        let shown = Instant::now();
+        loop {
+            let (control, step) = self.read_keys()?;
+            if let Control::Stop = control {
+                return Ok(Control::Stop);
+            }
+            if step || (!self.paused && shown.elapsed() >= self.delay) {
+                return Ok(Control::Continue);
+            }
+            if self.paused {
+                // shows the new state
+                self.draw(frame)?;
+            }
+            thread::sleep(KEY_POLL);
+        }
+    }
+}
+
+/// Raw mode swallows Ctrl-C, so it is raised again.
+/// With no part running that quits, the terminal is back to normal for it and only goes raw again if it didn't.
+fn interrupt(terminal: &Mutex<RawTerminal<io::Stdout>>) {
+    let mut terminal = terminal.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let _ = write!(terminal, "{}\r\n", termion::cursor::Show);
+    let _ = terminal.flush();
+    let _ = terminal.suspend_raw_mode();
+    let _ = signal_hook::low_level::raise(signal_hook::consts::SIGINT);
+    let _ = terminal.activate_raw_mode();
+    let _ = write!(terminal, "{}", termion::cursor::Hide);
+    let _ = terminal.flush();
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+        let mut terminal = self.terminal.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _ = write!(terminal, "{}\r\n", termion::cursor::Show);
+        let _ = terminal.flush();
+    }
+}
@@ -0,0 +1,59 @@
+//! The library as other projects see it, only through the public API
+
+use aoc::{
+    day::{self, y2023::d1_trebuchet::{cal_calibration, calibrate, Scanner, Vocabulary}},
+    geometry::{Direction, Pos},
+    grid::Grid,
+    prelude::*,
+};
+
+const DAY1: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+#[test]
+fn test_solutions() {
+    let solutions = day::solutions(day::DEFAULT_YEAR);
+    assert_eq!(solutions.len(), 25);
+    let day1 = day::solution(2023, 1).unwrap();
+    assert_eq!(day1.solve(Part::Two, DAY1).unwrap(), Answer::from(281_u32));
+    assert!(day::solution(2015, 1).is_none());
+}
+
+#[test]
+fn test_scanner() {
+    let scanner = Scanner::new(&Vocabulary::english().and(Vocabulary::german()));
+    assert_eq!(cal_calibration("eightwo\nzweiundvierzig", &scanner).unwrap(), 82 + 24);
+    assert!(cal_calibration("nothing", &scanner).is_err());
+}
+
+#[test]
+fn test_calibrate() {
+    let mut failed = Vec::new();
+    let calibration = calibrate(DAY1.as_bytes(), &Scanner::new(&Vocabulary::digits()), |failure| failed.push(failure.line)).unwrap();
+    assert_eq!(calibration.sum, 11 + 22 + 33 + 42 + 24 + 77);
+    assert_eq!(calibration.failed, 1);
+    assert_eq!(failed, vec![2]);
+}
+
+#[test]
+fn test_grid() {
+    let grid: Grid<char> = Grid::parse(&Source::new(0, "ab\ncd"), "ab\ncd").unwrap();
+    assert_eq!(grid[Pos::new(1, 0)], 'c');
+    assert_eq!(grid.step(Pos::new(0, 0), Direction::Right), Some(Pos::new(0, 1)));
+    assert_eq!(grid.transpose().to_string(), "ac\nbd");
+}
+
+#[test]
+fn test_errors() {
+    fn parse(day: &str) -> Result<u8> {
+        Ok(day.parse()?)
+    }
+    assert!(matches!(parse("x"), Err(AocError::ParseInt(_))));
+    let err: AocError = "broken".into();
+    assert_eq!(err.to_string(), "AocError: broken");
+}